@@ -1,12 +1,19 @@
 use criterion::{black_box, criterion_group, criterion_main, Criterion};
 
-use learning_graph::models::Command;
+use learning_graph::algorithms::visit::{IncomingNeighbours, Neighbours};
+use learning_graph::algorithms::{bidirectional_dijkstra, dijkstra};
+use learning_graph::command_result::CommandResult;
+use learning_graph::models::{Command, Link};
+use learning_graph::modes::ModeRegistry;
 use learning_graph::process_command::CommandProcessor;
-use learning_graph::startup::deserialization::{read_commands, read_links, read_places};
+use learning_graph::process_error::ProcessError;
+use learning_graph::startup::deserialization::{read_commands, Strictness};
 use learning_graph::startup::graph_builder;
+use learning_graph::startup::load_error::ErrorPolicy;
 
 fn run(processor: CommandProcessor, commands: Vec<Command>) {
-    let _: Vec<String> = commands.into_iter().map(|x| processor.process(x)).collect();
+    let _: Vec<Result<CommandResult, ProcessError>> =
+        commands.into_iter().map(|x| processor.process(x)).collect();
 }
 
 fn build() -> (CommandProcessor, Vec<Command>) {
@@ -14,17 +21,31 @@ fn build() -> (CommandProcessor, Vec<Command>) {
     let links_path = "Links.csv";
     let command_path = "Commands.txt";
 
-    let nodes = read_places(places_path);
-    let links = read_links(links_path);
+    let modes = ModeRegistry::default();
+    let (graph, map) =
+        graph_builder::load(places_path, links_path, ErrorPolicy::FailFast, &modes, None).unwrap();
 
-    let (graph, map) = graph_builder::build(nodes, links);
-
-    let commands = read_commands(command_path).unwrap();
+    let commands = read_commands(command_path, Strictness::Strict, &modes).unwrap();
 
     let processor = CommandProcessor::new(graph, map);
     (processor, commands)
 }
 
+// Shortest hop count from the first node to every other node.
+fn dijkstra_all<G: Neighbours<EdgeWeight = Link>>(graph: &G) {
+    dijkstra(graph, 0, None, |_| 1, |_| true);
+}
+
+// Shortest hop count from the first node to the last, searching from both ends.
+fn bidirectional_first_to_last<G: IncomingNeighbours<EdgeWeight = Link>>(graph: &G) {
+    bidirectional_dijkstra(graph, 0, graph.node_count() - 1, |_| 1, |_| true);
+}
+
+// Shortest hop count from the first node to the last, searching from the first only.
+fn dijkstra_first_to_last<G: Neighbours<EdgeWeight = Link>>(graph: &G) {
+    dijkstra(graph, 0, Some(graph.node_count() - 1), |_| 1, |_| true);
+}
+
 fn criterion_benchmark(c: &mut Criterion) {
     c.bench_function("run-all-commands", |b| {
         let (proc, commands) = build();
@@ -40,6 +61,63 @@ fn criterion_benchmark(c: &mut Criterion) {
         })
     });
 
+    let modes = ModeRegistry::default();
+
+    c.bench_function("dijkstra-graph", |b| {
+        let (graph, _) = graph_builder::load(
+            "Places.csv",
+            "Links.csv",
+            ErrorPolicy::FailFast,
+            &modes,
+            None,
+        )
+        .unwrap();
+
+        b.iter(|| dijkstra_all(black_box(&graph)))
+    });
+
+    c.bench_function("dijkstra-frozen-graph", |b| {
+        let (graph, _) = graph_builder::load(
+            "Places.csv",
+            "Links.csv",
+            ErrorPolicy::FailFast,
+            &modes,
+            None,
+        )
+        .unwrap();
+        let frozen = graph.freeze();
+
+        b.iter(|| dijkstra_all(black_box(&frozen)))
+    });
+
+    c.bench_function("point-to-point-dijkstra", |b| {
+        let (graph, _) = graph_builder::load(
+            "Places.csv",
+            "Links.csv",
+            ErrorPolicy::FailFast,
+            &modes,
+            None,
+        )
+        .unwrap();
+        let frozen = graph.freeze();
+
+        b.iter(|| dijkstra_first_to_last(black_box(&frozen)))
+    });
+
+    c.bench_function("point-to-point-bidirectional-dijkstra", |b| {
+        let (graph, _) = graph_builder::load(
+            "Places.csv",
+            "Links.csv",
+            ErrorPolicy::FailFast,
+            &modes,
+            None,
+        )
+        .unwrap();
+        let frozen = graph.freeze();
+
+        b.iter(|| bidirectional_first_to_last(black_box(&frozen)))
+    });
+
     c.bench_function("build-and-run-all-commands", |b| {
         b.iter(|| {
             let (proc, commands) = build();
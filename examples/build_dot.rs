@@ -2,24 +2,28 @@ use std::fmt;
 use std::fs::File;
 use std::io::Write;
 
-use learning_graph::models::{Link, Place, TravelMode};
+use learning_graph::models::{Link, Place};
 
-use learning_graph::startup::deserialization::{read_links, read_places};
+use learning_graph::modes::ModeRegistry;
 use learning_graph::startup::graph_builder::{self};
-use learning_graph::utils::dot::Dot;
+use learning_graph::startup::load_error::ErrorPolicy;
+use learning_graph::utils::dot::{mode_colour, write_mode_legend, Dot};
 
 //Run dot -Kfdp -n  example.dot -Tpng -o image.png
 // or dot example.dot -Tpng -o image.png
 fn main() {
     let places_path = "Places.csv";
     let links_path = "Links.csv";
+    let modes = ModeRegistry::default();
 
-    let nodes = read_places(places_path);
-    let links = read_links(links_path);
+    let (graph, _) =
+        graph_builder::load(places_path, links_path, ErrorPolicy::FailFast, &modes, None).unwrap();
 
-    let (graph, _) = graph_builder::build(nodes, links);
+    let fmt_link = |f: &mut fmt::Formatter, data: &Link| fmt_link(f, data, &modes);
+    let fmt_legend = |f: &mut fmt::Formatter| write_mode_legend(f, &modes);
+    let subgraphs: [&dyn Fn(&mut fmt::Formatter) -> fmt::Result; 1] = [&fmt_legend];
 
-    let dot = Dot::new(graph, &fmt_place, &fmt_link, &[&fmt_legend]);
+    let dot = Dot::new(graph, &fmt_place, &fmt_link, &subgraphs);
     println!("{}", dot);
 
     let mut f = File::create("example.dot").unwrap();
@@ -31,57 +35,11 @@ fn fmt_place(f: &mut fmt::Formatter, data: &Place) -> fmt::Result {
     write!(f, "label = \"{}\\n{}\" ", data.name, &data.id)
 }
 
-fn travel_to_color(mode: TravelMode) -> &'static str {
-    match mode {
-        TravelMode::Foot => "#772A53",
-        TravelMode::Bike => "#E9C46A",
-        TravelMode::Car => "#E76F51",
-        TravelMode::Bus => "#2A9D8F",
-        TravelMode::Ship => "#9D7892",
-        TravelMode::Rail => "#073B74",
-    }
-}
-
-fn fmt_link(f: &mut fmt::Formatter, data: &Link) -> fmt::Result {
+fn fmt_link(f: &mut fmt::Formatter, data: &Link, modes: &ModeRegistry) -> fmt::Result {
     write!(
         f,
         "color = \"{}\" penwidth=\"8\" label=\"{}\"",
-        travel_to_color(data.mode),
+        mode_colour(modes, &data.mode),
         data.mode
     )
 }
-
-fn fmt_legend(f: &mut fmt::Formatter) -> fmt::Result {
-    let a = &[
-        TravelMode::Foot,
-        TravelMode::Bike,
-        TravelMode::Car,
-        TravelMode::Bus,
-        TravelMode::Ship,
-        TravelMode::Rail,
-    ];
-
-    write!(
-        f,
-        "subgraph cluster_01 {{\nlabel = \"Legend\";\nnode [shape=point]\n{{\nrank=same\n"
-    )?;
-
-    for mode in a {
-        write!(
-            f,
-            "{}0 [style = invis];\n{}1 [style = invis];\n",
-            mode, mode
-        )?;
-    }
-    writeln!(f, "}}")?;
-
-    for mode in a {
-        writeln!(
-            f,
-            "{0}0 -- {0}1 [label={0} color=\"{1}\" penwidth=\"8\"] ",
-            mode,
-            travel_to_color(*mode)
-        )?;
-    }
-    writeln!(f, "}}")
-}
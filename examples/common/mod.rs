@@ -0,0 +1,86 @@
+//! Networks shared by the example tests.
+
+// Each example uses only some of these.
+#![allow(dead_code)]
+
+use std::collections::HashMap;
+
+use learning_graph::{
+    algorithms::graph::{Graph, NodeIndex},
+    models::{Link, Place, TravelMode},
+    process_command::CommandProcessor,
+};
+
+/// A graph of places on the grid and the id map a `CommandProcessor` needs.
+pub struct Network {
+    pub graph: Graph<Place, Link>,
+    pub map: HashMap<i32, NodeIndex>,
+}
+
+impl Network {
+    pub fn new() -> Self {
+        Self {
+            graph: Graph::new(),
+            map: HashMap::new(),
+        }
+    }
+
+    /// Adds a place named after its id, at a grid position in metres.
+    pub fn place(&mut self, id: i32, northings: f64, eastings: f64) -> &mut Self {
+        let place = Place::new(id.to_string(), id, 0.0, 0.0, northings, eastings);
+        self.map.insert(id, self.graph.add_node(place));
+        self
+    }
+
+    /// Adds a two-way link of `mode` between two places already added.
+    pub fn link(&mut self, start: i32, end: i32, mode: &str) -> &mut Self {
+        self.add_link(Link::new(start, end, TravelMode::new(mode)))
+    }
+
+    /// Adds `link` between its places, one way when the link says so.
+    pub fn add_link(&mut self, link: Link) -> &mut Self {
+        let (start, end) = (self.map[&link.start], self.map[&link.end]);
+        if link.is_one_way() {
+            self.graph.add_directed_edge(start, end, link);
+        } else {
+            self.graph.add_edge(start, end, link);
+        }
+        self
+    }
+
+    pub fn processor(self) -> CommandProcessor {
+        CommandProcessor::new(self.graph, self.map)
+    }
+}
+
+/// Writes `contents` to a file called `name` in the temporary directory, returning
+/// its path.
+pub fn write_temp(name: &str, contents: &str) -> String {
+    let path = std::env::temp_dir().join(name);
+    std::fs::write(&path, contents).unwrap();
+    path.to_str().unwrap().to_string()
+}
+
+/// A square of 10km sides with one diagonal, linked by car:
+///
+/// ```text
+/// 4 --- 3
+/// |   / |
+/// |  /  |
+/// | /   |
+/// 1 --- 2
+/// ```
+pub fn square() -> Network {
+    let mut network = Network::new();
+    network
+        .place(1, 0.0, 0.0)
+        .place(2, 0.0, 10000.0)
+        .place(3, 10000.0, 10000.0)
+        .place(4, 10000.0, 0.0);
+
+    for (start, end) in [(1, 2), (2, 3), (3, 4), (4, 1), (1, 3)] {
+        network.link(start, end, "Car");
+    }
+
+    network
+}
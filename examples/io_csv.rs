@@ -1,18 +1,25 @@
+use learning_graph::modes::ModeRegistry;
 use learning_graph::startup::deserialization::{read_links, read_places};
+use learning_graph::startup::load_error::ErrorPolicy;
 
 fn main() {
     let places_path = "Places.csv";
     let links_path = "Links.csv";
 
-    let places = read_places(places_path);
+    let places = read_places(places_path, ErrorPolicy::CollectAll, None).unwrap();
 
     for place in places {
         println!("{:?}", place);
     }
 
-    let links = read_links(links_path);
+    let links = read_links(
+        links_path,
+        ErrorPolicy::CollectAll,
+        &ModeRegistry::default(),
+    )
+    .unwrap();
 
-    for link in links {
-        println!("{:?}", link);
+    for (line, link) in links {
+        println!("{}: {:?}", line, link);
     }
 }
@@ -1,5 +1,6 @@
 use std::fs::{self};
 
+use learning_graph::modes::ModeRegistry;
 use learning_graph::startup::parser::parse_command;
 
 fn main() {
@@ -7,11 +8,12 @@ fn main() {
         fs::read_to_string("Commands.txt").expect("Something went wrong reading the file");
 
     let lines = contents.lines();
+    let modes = ModeRegistry::default();
 
     for line in lines {
         println!("{:?}", line);
 
-        let parsed = parse_command(line.as_bytes());
+        let parsed = parse_command(&modes, line.as_bytes());
         println!("{:?}", parsed.expect("Error parsing").1);
     }
 }
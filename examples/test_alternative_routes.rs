@@ -0,0 +1,105 @@
+use learning_graph::algorithms::{graph::Graph, k_shortest_paths};
+
+extern crate learning_graph;
+
+mod common;
+
+/// The directed network from the Wikipedia article on Yen's algorithm.
+fn network() -> Graph<&'static str, i32> {
+    let mut graph = Graph::new();
+
+    let c = graph.add_node("C");
+    let d = graph.add_node("D");
+    let e = graph.add_node("E");
+    let f = graph.add_node("F");
+    let g = graph.add_node("G");
+    let h = graph.add_node("H");
+
+    graph.add_directed_edge(c, d, 3);
+    graph.add_directed_edge(c, e, 2);
+    graph.add_directed_edge(d, f, 4);
+    graph.add_directed_edge(e, d, 1);
+    graph.add_directed_edge(e, f, 2);
+    graph.add_directed_edge(e, g, 3);
+    graph.add_directed_edge(f, g, 2);
+    graph.add_directed_edge(f, h, 1);
+    graph.add_directed_edge(g, h, 2);
+
+    graph
+}
+
+fn main() {
+    let graph = network();
+
+    for (cost, path) in k_shortest_paths(&graph, 0, 5, 3, |x| *x, |_| true) {
+        let names: Vec<_> = path
+            .iter()
+            .map(|x| graph.get_node(*x).unwrap().data)
+            .collect();
+        println!("{} {}", cost, names.join("-"));
+    }
+}
+
+#[test]
+fn test_k_shortest_paths() {
+    let graph = network();
+    let (c, d, e, f, g, h) = (0, 1, 2, 3, 4, 5);
+
+    let result = k_shortest_paths(&graph, c, h, 3, |x| *x, |_| true);
+    assert_eq!(
+        result,
+        vec![
+            (5, vec![c, e, f, h]),
+            (7, vec![c, e, g, h]),
+            (8, vec![c, d, f, h]),
+        ]
+    );
+
+    // Every loopless route, then nothing more.
+    let result = k_shortest_paths(&graph, c, h, 100, |x| *x, |_| true);
+    assert_eq!(result.len(), 7);
+    assert!(result.windows(2).all(|x| x[0].0 <= x[1].0));
+
+    // Without the edges costing 2, E cannot be reached and the route is through D.
+    let result = k_shortest_paths(&graph, c, h, 1, |x| *x, |x| *x != 2);
+    assert_eq!(result, vec![(8, vec![c, d, f, h])]);
+
+    assert!(k_shortest_paths(&graph, h, c, 3, |x| *x, |_| true).is_empty());
+    assert!(k_shortest_paths(&graph, c, h, 0, |x| *x, |_| true).is_empty());
+}
+
+#[test]
+fn test_find_alternative_routes() {
+    use learning_graph::{
+        command_result::CommandResult, modes::ModeRegistry, startup::parser::parse_line,
+    };
+
+    let processor = common::square().processor();
+    let command = parse_line(
+        &ModeRegistry::default(),
+        1,
+        "FindAlternativeRoutes Car 1 3 5",
+    )
+    .unwrap();
+
+    let result = processor.process(command).unwrap();
+    let routes = match &result {
+        CommandResult::AlternativeRoutes { routes, .. } => routes,
+        _ => panic!("expected routes, got {:?}", result),
+    };
+
+    let hops: Vec<usize> = routes.iter().map(|x| x.hops()).collect();
+    assert_eq!(hops, vec![1, 2, 2]);
+    assert!((routes[0].distance - 200f64.sqrt()).abs() < 1e-9);
+    assert!((routes[1].distance - 20.0).abs() < 1e-9);
+    assert!(result
+        .to_string()
+        .starts_with("FindAlternativeRoutes Car 1 3 5\n1,1,14.142,1 3\n"));
+
+    assert!(parse_line(
+        &ModeRegistry::default(),
+        1,
+        "FindAlternativeRoutes Car 1 3 0"
+    )
+    .is_err());
+}
@@ -0,0 +1,76 @@
+use learning_graph::algorithms::{astar, graph::Graph};
+
+extern crate learning_graph;
+
+mod common;
+
+fn main() {
+    let mut graph = Graph::new();
+
+    let node_a = graph.add_node(0);
+    let node_b = graph.add_node(2);
+    let node_c = graph.add_node(1);
+    let node_d = graph.add_node(0);
+
+    graph.add_edge(node_a, node_b, 1);
+    graph.add_edge(node_a, node_c, 2);
+    graph.add_edge(node_b, node_d, 4);
+    graph.add_edge(node_c, node_d, 1);
+
+    let result = astar(&graph, node_a, node_d, |x| *x, |x| *x, |_| true);
+
+    println!("{:?}", result);
+}
+
+#[test]
+fn test_astar_matches_dijkstra() {
+    use learning_graph::algorithms::dijkstra;
+
+    let mut graph = Graph::new();
+
+    let node_a = graph.add_node(0);
+    let node_b = graph.add_node(2);
+    let node_c = graph.add_node(1);
+    let node_d = graph.add_node(0);
+
+    graph.add_edge(node_a, node_b, 1);
+    graph.add_edge(node_a, node_c, 2);
+    graph.add_edge(node_b, node_d, 4);
+    graph.add_edge(node_c, node_d, 1);
+
+    let result = astar(&graph, node_a, node_d, |x| *x, |x| *x, |_| true);
+    let expected = dijkstra(&graph, node_a, None, |x| *x, |_| true);
+
+    assert_eq!(result.path_to(node_d), Some(vec![node_a, node_c, node_d]));
+    assert_eq!(result.cost_to(node_d), Some(3));
+    assert_eq!(result.cost_to(node_d), expected.cost_to(node_d));
+}
+
+#[test]
+fn test_straight_line_heuristic() {
+    use learning_graph::{
+        algorithms::astar::straight_line_heuristic, distance::DistanceModel, models::Link,
+    };
+    use ordered_float::OrderedFloat;
+
+    // A 3-4-5 triangle, with the longer way round through 2.
+    let mut network = common::Network::new();
+    network
+        .place(1, 0.0, 0.0)
+        .place(2, 0.0, 4000.0)
+        .place(3, 3000.0, 4000.0)
+        .link(1, 2, "Rail")
+        .link(2, 3, "Rail")
+        .link(1, 3, "Rail");
+    let (graph, map) = (&network.graph, &network.map);
+    let place = |id| &graph.get_node(map[&id]).unwrap().data;
+
+    let heuristic = straight_line_heuristic(place(3), DistanceModel::Projected);
+    assert_eq!(heuristic(place(1)), OrderedFloat(5.0));
+    assert_eq!(heuristic(place(3)), OrderedFloat(0.0));
+
+    let length = |link: &Link| OrderedFloat(place(link.start).distance(place(link.end)));
+    let result = astar(graph, map[&1], map[&3], length, heuristic, |_| true);
+    assert_eq!(result.path_to(map[&3]), Some(vec![map[&1], map[&3]]));
+    assert_eq!(result.cost_to(map[&3]), Some(OrderedFloat(5.0)));
+}
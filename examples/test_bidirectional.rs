@@ -0,0 +1,74 @@
+use learning_graph::algorithms::{bidirectional_dijkstra, graph::Graph};
+
+extern crate learning_graph;
+
+fn main() {
+    let mut graph = Graph::new();
+
+    let node_a = graph.add_node("a");
+    let node_b = graph.add_node("b");
+    let node_c = graph.add_node("c");
+    let node_d = graph.add_node("d");
+    let node_e = graph.add_node("e");
+
+    graph.add_edge(node_a, node_d, 1);
+    graph.add_edge(node_a, node_b, 6);
+    graph.add_edge(node_d, node_b, 2);
+    graph.add_edge(node_d, node_e, 1);
+    graph.add_edge(node_e, node_b, 2);
+    graph.add_edge(node_e, node_c, 5);
+    graph.add_edge(node_b, node_c, 5);
+
+    let result = bidirectional_dijkstra(&graph, node_a, node_c, |x| *x, |_| true);
+
+    println!("{:?}", result);
+}
+
+#[test]
+fn test_bidirectional_matches_dijkstra() {
+    use learning_graph::algorithms::dijkstra;
+
+    // A grid with pseudo-random weights and some one-way edges.
+    const SIZE: usize = 8;
+    let mut graph = Graph::new();
+    let nodes: Vec<_> = (0..SIZE * SIZE).map(|x| graph.add_node(x)).collect();
+
+    let mut seed = 17u32;
+    let mut weight = || {
+        seed = seed.wrapping_mul(1_103_515_245).wrapping_add(12_345);
+        (seed >> 16) % 9 + 1
+    };
+    for row in 0..SIZE {
+        for col in 0..SIZE {
+            let node = nodes[row * SIZE + col];
+            if col + 1 < SIZE {
+                graph.add_edge(node, nodes[row * SIZE + col + 1], weight());
+            }
+            if row + 1 < SIZE {
+                let below = nodes[(row + 1) * SIZE + col];
+                if (row + col) % 3 == 0 {
+                    graph.add_directed_edge(below, node, weight());
+                } else {
+                    graph.add_edge(node, below, weight());
+                }
+            }
+        }
+    }
+
+    for &start in &nodes {
+        let scores = dijkstra(&graph, start, None, |x| *x, |_| true);
+
+        for &goal in &nodes {
+            let result = bidirectional_dijkstra(&graph, start, goal, |x| *x, |_| true);
+            let (cost, path) = result.unwrap();
+
+            assert_eq!(Some(cost), scores.cost_to(goal));
+            assert_eq!(path.first(), Some(&start));
+            assert_eq!(path.last(), Some(&goal));
+        }
+    }
+
+    // No edge is traversable, so no other node can be reached.
+    let result = bidirectional_dijkstra(&graph, nodes[0], nodes[SIZE], |x| *x, |x| *x > 100);
+    assert_eq!(result, None);
+}
@@ -0,0 +1,152 @@
+use learning_graph::startup::cli::Options;
+
+extern crate learning_graph;
+
+fn parse(args: &[&str]) -> Result<Options, String> {
+    Options::parse(args.iter().map(|x| x.to_string()))
+}
+
+fn main() {
+    println!("{:?}", parse(&["repl", "-f", "json"]));
+}
+
+#[test]
+fn test_parse_options() {
+    use learning_graph::{
+        distance::DistanceModel, projection::Projection, startup::cli::OutputFormat,
+    };
+
+    assert_eq!(parse(&[]), Ok(Options::default()));
+
+    let options = parse(&[
+        "-p",
+        "p.csv",
+        "-l",
+        "l.csv",
+        "-c",
+        "c.txt",
+        "-m",
+        "m.json",
+        "-o",
+        "o.txt",
+        "-f",
+        "json",
+        "-d",
+        "haversine",
+        "--projection",
+        "bng",
+        "--strict",
+        "-h",
+    ])
+    .unwrap();
+    assert_eq!(
+        options,
+        Options {
+            places: "p.csv".to_string(),
+            links: "l.csv".to_string(),
+            commands: Some("c.txt".to_string()),
+            modes: Some("m.json".to_string()),
+            output: Some("o.txt".to_string()),
+            format: OutputFormat::Json,
+            distance: DistanceModel::Haversine,
+            projection: Some(Projection::BritishNationalGrid),
+            strict: true,
+            repl: false,
+            help: true,
+        }
+    );
+
+    let long = parse(&[
+        "--places",
+        "p.csv",
+        "--links",
+        "l.csv",
+        "--commands",
+        "c.txt",
+        "--modes",
+        "m.json",
+        "--output",
+        "o.txt",
+        "--format",
+        "json",
+        "--distance",
+        "haversine",
+        "--projection",
+        "bng",
+        "--strict",
+        "--help",
+    ]);
+    assert_eq!(long, Ok(options));
+
+    let options = parse(&["-d", "vincenty", "--projection", "utm:31S"]).unwrap();
+    assert_eq!(options.distance, DistanceModel::Vincenty);
+    assert_eq!(
+        options.projection,
+        Some(Projection::Utm {
+            zone: 31,
+            south: true
+        })
+    );
+    assert_eq!(
+        parse(&["--projection", "utm:31s"]).unwrap().projection,
+        options.projection
+    );
+    assert_eq!(
+        parse(&["--projection", "utm:60n"]).unwrap().projection,
+        Some(Projection::Utm {
+            zone: 60,
+            south: false
+        })
+    );
+    assert_eq!(parse(&["--projection", "utm"]).unwrap().projection, None);
+}
+
+#[test]
+fn test_parse_repl() {
+    let options = parse(&["repl", "-p", "p.csv"]).unwrap();
+    assert!(options.repl);
+    assert_eq!(options.places, "p.csv");
+
+    // `repl` is only recognised before the options.
+    assert_eq!(
+        parse(&["-p", "p.csv", "repl"]),
+        Err("unexpected argument 'repl'".to_string())
+    );
+}
+
+#[test]
+fn test_parse_errors() {
+    assert_eq!(parse(&["-p"]), Err("missing value for '-p'".to_string()));
+    assert_eq!(
+        parse(&["--format"]),
+        Err("missing value for '--format'".to_string())
+    );
+    assert_eq!(
+        parse(&["--verbose"]),
+        Err("unexpected argument '--verbose'".to_string())
+    );
+    assert_eq!(
+        parse(&["-f", "xml"]),
+        Err("unknown output format 'xml'".to_string())
+    );
+    assert_eq!(
+        parse(&["-d", "flat"]),
+        Err("unknown distance model 'flat'".to_string())
+    );
+    for projection in ["utm:0", "utm:61", "utm:31X", "osgb"] {
+        assert_eq!(
+            parse(&["--projection", projection]),
+            Err(format!("unknown projection '{}'", projection))
+        );
+    }
+}
+
+#[test]
+fn test_parse_repeated_flag() {
+    // The last value given wins.
+    let options = parse(&["-p", "a.csv", "--places", "b.csv"]).unwrap();
+    assert_eq!(options.places, "b.csv");
+
+    let options = parse(&["--strict", "--strict"]).unwrap();
+    assert!(options.strict);
+}
@@ -0,0 +1,224 @@
+use learning_graph::{
+    command_result::{
+        AlternativeRoute, CheckLeg, CommandResult, DistinctRoute, RouteLeg, TimedLeg, ViaSegment,
+    },
+    models::{Place, TravelMode},
+};
+
+extern crate learning_graph;
+
+fn place(id: i32, northings: f64, eastings: f64) -> Place {
+    Place::new(format!("Place {}", id), id, 0.0, 0.0, northings, eastings)
+}
+
+/// One result of each kind with its text output. The original commands give the
+/// text they have always given, including their failed forms.
+fn results() -> Vec<(CommandResult, &'static str)> {
+    let rail = TravelMode::new("Rail");
+
+    vec![
+        (
+            CommandResult::MaxDist {
+                a: place(1, 0.0, 0.0),
+                b: place(2, 3000.0, 4000.0),
+                distance: 5.0,
+            },
+            "MaxDist\n\
+             Place { name: \"Place 1\", id: 1, northings: 0.0, eastings: 0.0 }, \
+             Place { name: \"Place 2\", id: 2, northings: 3000.0, eastings: 4000.0 }, 5",
+        ),
+        (
+            CommandResult::MaxLink {
+                a: 1,
+                b: 2,
+                distance: 5.04,
+            },
+            "MaxLink\n1,2,5.0",
+        ),
+        (
+            CommandResult::FindDist {
+                a: place(1, 0.0, 0.0),
+                b: place(2, 3000.0, 4000.0),
+                distance: 5.0,
+            },
+            "FindDist\nPlace 1,Place 2,5.000",
+        ),
+        (
+            CommandResult::FindNeighbour {
+                place: 1,
+                neighbours: vec![2, 3],
+            },
+            "FindNeighbour 1\n2\n3",
+        ),
+        (
+            CommandResult::FindNeighbour {
+                place: 1,
+                neighbours: vec![],
+            },
+            "FindNeighbour 1",
+        ),
+        (
+            CommandResult::Check {
+                mode: rail.clone(),
+                places: vec![1, 2, 3],
+                legs: vec![
+                    CheckLeg {
+                        from: 1,
+                        to: 2,
+                        connects: true,
+                    },
+                    CheckLeg {
+                        from: 2,
+                        to: 3,
+                        connects: false,
+                    },
+                ],
+            },
+            "Check Rail 1 2 3\n1,2,PASS\n2,3,FAIL",
+        ),
+        (
+            CommandResult::Route {
+                mode: rail.clone(),
+                start: 1,
+                goal: 2,
+                route: vec![place(1, 0.0, 0.0), place(2, 3000.0, 4000.0)],
+            },
+            "FindRoute Rail 1 2\n\
+             Place { name: \"Place 1\", id: 1, northings: 0.0, eastings: 0.0 }\n\
+             Place { name: \"Place 2\", id: 2, northings: 3000.0, eastings: 4000.0 }",
+        ),
+        (
+            CommandResult::ShortestRoute {
+                mode: rail.clone(),
+                start: 1,
+                goal: 2,
+                route: vec![place(1, 0.0, 0.0)],
+            },
+            "FindShortestRoute Rail 1 2\n\
+             Place { name: \"Place 1\", id: 1, northings: 0.0, eastings: 0.0 }",
+        ),
+        (
+            CommandResult::DistanceRoute {
+                mode: rail.clone(),
+                start: 1,
+                goal: 2,
+                legs: vec![RouteLeg {
+                    from: place(1, 0.0, 0.0),
+                    to: place(2, 3000.0, 4000.0),
+                    distance: 5.0,
+                }],
+            },
+            "FindShortestDistanceRoute Rail 1 2\n1,2,5.000\nTotal,5.000",
+        ),
+        (
+            CommandResult::FastestRoute {
+                mode: rail.clone(),
+                start: 1,
+                goal: 2,
+                legs: vec![TimedLeg {
+                    from: place(1, 0.0, 0.0),
+                    to: place(2, 3000.0, 4000.0),
+                    mode: rail.clone(),
+                    distance: 5.0,
+                    minutes: 3.0,
+                }],
+            },
+            "FindFastestRoute Rail 1 2\n1,2,Rail,3.0\nTotal,3.0",
+        ),
+        (
+            CommandResult::RouteVia {
+                mode: rail.clone(),
+                places: vec![1, 2],
+                segments: vec![ViaSegment {
+                    from: 1,
+                    to: 2,
+                    legs: Some(vec![RouteLeg {
+                        from: place(1, 0.0, 0.0),
+                        to: place(2, 3000.0, 4000.0),
+                        distance: 5.0,
+                    }]),
+                }],
+                route: Some(vec![place(1, 0.0, 0.0), place(2, 3000.0, 4000.0)]),
+            },
+            "FindRouteVia Rail 1 2\n1,2,5.000,1 2\nTotal,5.000,1 2",
+        ),
+        (
+            CommandResult::AlternativeRoutes {
+                mode: rail.clone(),
+                start: 1,
+                goal: 2,
+                count: 2,
+                routes: vec![AlternativeRoute {
+                    places: vec![place(1, 0.0, 0.0), place(2, 3000.0, 4000.0)],
+                    distance: 5.0,
+                }],
+            },
+            "FindAlternativeRoutes Rail 1 2 2\n1,1,5.000,1 2",
+        ),
+        (
+            CommandResult::DistinctRoutes {
+                mode: rail,
+                start: 1,
+                goal: 2,
+                count: 2,
+                max_overlap: 0.5,
+                max_stretch: 1.5,
+                routes: vec![DistinctRoute {
+                    places: vec![place(1, 0.0, 0.0), place(2, 3000.0, 4000.0)],
+                    distance: 5.0,
+                    overlap: 0.0,
+                }],
+            },
+            "FindDistinctRoutes Rail 1 2 2 0.5 1.5\n1,1,5.000,0.000,1 2",
+        ),
+    ]
+}
+
+fn main() {
+    for (result, _) in results() {
+        println!("{}\n", result);
+    }
+}
+
+#[test]
+fn test_text_output() {
+    for (result, expected) in results() {
+        assert_eq!(result.to_string(), expected);
+    }
+}
+
+#[test]
+fn test_json_output() {
+    use serde_json::json;
+
+    // Each result is tagged with the name of the command that gave it.
+    for (result, expected) in results() {
+        let value = serde_json::to_value(&result).unwrap();
+        let name = expected.split([' ', '\n']).next().unwrap();
+        assert_eq!(value["command"], name, "{}", expected);
+    }
+
+    let result = CommandResult::ShortestRoute {
+        mode: TravelMode::new("Rail"),
+        start: 1,
+        goal: 2,
+        route: vec![place(1, 0.0, 0.0)],
+    };
+    assert_eq!(
+        serde_json::to_value(&result).unwrap(),
+        json!({
+            "command": "FindShortestRoute",
+            "mode": "Rail",
+            "start": 1,
+            "goal": 2,
+            "route": [{
+                "name": "Place 1",
+                "id": 1,
+                "latitude": 0.0,
+                "longitude": 0.0,
+                "northings": 0.0,
+                "eastings": 0.0
+            }]
+        })
+    );
+}
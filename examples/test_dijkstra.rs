@@ -50,16 +50,56 @@ fn test_disktra() {
 
     let result = dijkstra(&graph, node_a, None, |x| *x, |_| true);
 
-    let expected = [
-        (0, (0, 0)),
-        (1, (3, 3)),
-        (2, (7, 4)),
-        (3, (1, 0)),
-        (4, (2, 3)),
-    ]
-    .iter()
-    .cloned()
-    .collect();
-
-    assert_eq!(result, expected);
+    let expected = [(0, 0), (1, 3), (2, 7), (3, 1), (4, 2)];
+    for (node, cost) in expected {
+        assert_eq!(result.cost_to(node), Some(cost));
+    }
+    assert_eq!(result.len(), expected.len());
+
+    assert_eq!(result.root(), node_a);
+    assert_eq!(result.predecessor(node_a), None);
+    assert_eq!(result.predecessor(node_b), Some(node_d));
+    assert_eq!(result.path_to(node_a), Some(vec![node_a]));
+    assert_eq!(
+        result.path_to(node_c),
+        Some(vec![node_a, node_d, node_e, node_c])
+    );
+
+    let mut reached: Vec<_> = result.reached().collect();
+    reached.sort_unstable();
+    assert_eq!(reached, vec![node_a, node_b, node_c, node_d, node_e]);
+
+    let total: i32 = result.iter().map(|(_, cost)| cost).sum();
+    assert_eq!(total, 13);
+}
+
+#[test]
+fn test_dijkstra_frozen() {
+    let mut graph = Graph::new();
+
+    let node_a = graph.add_node("a");
+    let node_b = graph.add_node("b");
+    let node_c = graph.add_node("c");
+    let node_d = graph.add_node("d");
+
+    graph.add_edge(node_a, node_b, 4);
+    graph.add_edge(node_a, node_d, 1);
+    graph.add_edge(node_d, node_b, 1);
+    graph.add_directed_edge(node_c, node_b, 1);
+
+    let frozen = graph.freeze();
+
+    for start in [node_a, node_b, node_c, node_d] {
+        let expected = dijkstra(&graph, start, None, |x| *x, |_| true);
+        let result = dijkstra(&frozen, start, None, |x| *x, |_| true);
+
+        assert_eq!(result, expected);
+    }
+
+    let from_b = dijkstra(&frozen, node_b, None, |x| *x, |_| true);
+    assert_eq!(from_b.path_to(node_c), None);
+    assert_eq!(from_b.cost_to(node_c), None);
+
+    assert!(frozen.is_adjacent(node_c, node_b));
+    assert!(!frozen.is_adjacent(node_b, node_c));
 }
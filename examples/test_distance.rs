@@ -0,0 +1,40 @@
+use learning_graph::{distance::DistanceModel, models::Place};
+
+extern crate learning_graph;
+
+fn main() {
+    let york = Place::new("York".to_string(), 1, 53.958, -1.093, 0.0, 0.0);
+    let zeebrugge = Place::new("Zeebrugge".to_string(), 2, 51.333, 3.207, 0.0, 0.0);
+
+    for model in [DistanceModel::Haversine, DistanceModel::Vincenty] {
+        println!("{:?}: {:.3}km", model, model.distance(&york, &zeebrugge));
+    }
+}
+
+#[test]
+fn test_distance_models() {
+    use learning_graph::distance::{haversine, vincenty};
+
+    let degrees = |degrees: f64, minutes: f64, seconds: f64| {
+        degrees.signum() * (degrees.abs() + minutes / 60.0 + seconds / 3600.0)
+    };
+
+    // Flinders Peak to Buninyong, the worked example from Vincenty's paper: 54972.271m.
+    let (lat_a, lon_a) = (degrees(-37.0, 57.0, 3.7203), degrees(144.0, 25.0, 29.5244));
+    let (lat_b, lon_b) = (degrees(-37.0, 39.0, 10.1561), degrees(143.0, 55.0, 35.3839));
+    assert!((vincenty(lat_a, lon_a, lat_b, lon_b) - 54.972271).abs() < 1e-6);
+
+    // The haversine sphere is within half a percent of the ellipsoid.
+    let sphere = haversine(lat_a, lon_a, lat_b, lon_b);
+    assert!((sphere - 54.972271).abs() / 54.972271 < 0.005);
+
+    assert_eq!(vincenty(lat_a, lon_a, lat_a, lon_a), 0.0);
+    assert_eq!(haversine(lat_a, lon_a, lat_a, lon_a), 0.0);
+
+    // Places are measured by their latitude/longitude or by their grid positions.
+    let a = Place::new("a".to_string(), 1, 0.0, 0.0, 0.0, 0.0);
+    let b = Place::new("b".to_string(), 2, 0.0, 1.0, 0.0, 3000.0);
+    assert_eq!(DistanceModel::Projected.distance(&a, &b), 3.0);
+    assert!((DistanceModel::Haversine.distance(&a, &b) - 111.195).abs() < 1e-3);
+    assert!((DistanceModel::Vincenty.distance(&a, &b) - 111.319).abs() < 1e-3);
+}
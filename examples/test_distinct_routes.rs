@@ -0,0 +1,192 @@
+use learning_graph::algorithms::{distinct_paths, graph::Graph, DistinctPathLimits};
+
+extern crate learning_graph;
+
+mod common;
+
+/// A corridor from S to T with a short detour around A-B, and a slower corridor
+/// through P and Q that shares nothing with it.
+fn network() -> Graph<&'static str, f64> {
+    let mut graph = Graph::new();
+
+    let s = graph.add_node("S");
+    let a = graph.add_node("A");
+    let b = graph.add_node("B");
+    let t = graph.add_node("T");
+    let x = graph.add_node("X");
+    let p = graph.add_node("P");
+    let q = graph.add_node("Q");
+
+    graph.add_edge(s, a, 1.0);
+    graph.add_edge(a, b, 1.0);
+    graph.add_edge(b, t, 1.0);
+    graph.add_edge(a, x, 1.1);
+    graph.add_edge(x, b, 1.0);
+    graph.add_edge(s, p, 1.4);
+    graph.add_edge(p, q, 1.4);
+    graph.add_edge(q, t, 1.4);
+
+    graph
+}
+
+fn main() {
+    let graph = network();
+    let limits = DistinctPathLimits::default();
+
+    for path in distinct_paths(&graph, 0, 3, 3, limits, |x| *x, |_| true) {
+        let names: Vec<_> = path
+            .nodes
+            .iter()
+            .map(|x| graph.get_node(*x).unwrap().data)
+            .collect();
+        println!("{:.1} {:.2} {}", path.cost, path.overlap, names.join("-"));
+    }
+}
+
+#[test]
+fn test_distinct_paths() {
+    let graph = network();
+    let (s, a, b, t, x, p, q) = (0, 1, 2, 3, 4, 5, 6);
+
+    // The detour shares S-A and B-T, 2 of its 4.1, with the cheapest route.
+    let limits = DistinctPathLimits {
+        max_overlap: 0.6,
+        max_stretch: 2.0,
+        ..DistinctPathLimits::default()
+    };
+    let result = distinct_paths(&graph, s, t, 3, limits, |x| *x, |_| true);
+    let nodes: Vec<_> = result.iter().map(|x| x.nodes.clone()).collect();
+    assert_eq!(
+        nodes,
+        vec![vec![s, a, b, t], vec![s, a, x, b, t], vec![s, p, q, t]]
+    );
+    assert!((result[1].overlap - 2.0 / 4.1).abs() < 1e-9);
+    assert_eq!(result[2].overlap, 0.0);
+
+    // Too much overlap for the detour.
+    let limits = DistinctPathLimits {
+        max_overlap: 0.3,
+        ..limits
+    };
+    let result = distinct_paths(&graph, s, t, 3, limits, |x| *x, |_| true);
+    let nodes: Vec<_> = result.iter().map(|x| x.nodes.clone()).collect();
+    assert_eq!(nodes, vec![vec![s, a, b, t], vec![s, p, q, t]]);
+
+    // Too long for the corridor through P and Q.
+    let limits = DistinctPathLimits {
+        max_stretch: 1.2,
+        ..limits
+    };
+    let result = distinct_paths(&graph, s, t, 3, limits, |x| *x, |_| true);
+    assert_eq!(result.len(), 1);
+    assert_eq!(result[0].cost, 3.0);
+
+    let result = distinct_paths(&graph, t, s, 3, limits, |x| *x, |x| *x < 1.2);
+    assert_eq!(result.len(), 1);
+    assert_eq!(result[0].nodes, vec![t, b, a, s]);
+    assert!(distinct_paths(&graph, s, t, 0, limits, |x| *x, |_| true).is_empty());
+    assert!(distinct_paths(&graph, s, p, 3, limits, |x| *x, |x| *x < 1.2).is_empty());
+}
+
+#[test]
+fn test_overlap_with_cheapest() {
+    // The cheapest route is the direct S-T. S-P-Q-T and S-P-Q-R-T share S-P-Q with
+    // each other but nothing with it.
+    let mut graph = Graph::new();
+    let (s, t, p, q, r) = (
+        graph.add_node("S"),
+        graph.add_node("T"),
+        graph.add_node("P"),
+        graph.add_node("Q"),
+        graph.add_node("R"),
+    );
+    graph.add_edge(s, t, 3.0);
+    graph.add_edge(s, p, 1.0);
+    graph.add_edge(p, q, 1.0);
+    graph.add_edge(q, t, 1.5);
+    graph.add_edge(q, r, 0.8);
+    graph.add_edge(r, t, 0.9);
+
+    let limits = DistinctPathLimits {
+        max_overlap: 0.3,
+        max_stretch: 2.0,
+        ..DistinctPathLimits::default()
+    };
+    let result = distinct_paths(&graph, s, t, 3, limits, |x| *x, |_| true);
+    let nodes: Vec<_> = result.iter().map(|x| x.nodes.clone()).collect();
+    assert_eq!(
+        nodes,
+        vec![vec![s, t], vec![s, p, q, t], vec![s, p, q, r, t]]
+    );
+    assert!(result.iter().all(|x| x.overlap == 0.0));
+    assert!(result.iter().all(|x| x.overlap.is_sign_positive()));
+
+    // One way links out to C and D force the longest route back along B-A, against
+    // the direction the cheapest route travels it.
+    let mut graph = Graph::new();
+    let (s, a, b, t, c, d) = (
+        graph.add_node("S"),
+        graph.add_node("A"),
+        graph.add_node("B"),
+        graph.add_node("T"),
+        graph.add_node("C"),
+        graph.add_node("D"),
+    );
+    graph.add_directed_edge(s, a, 1.0);
+    graph.add_edge(a, b, 1.0);
+    graph.add_directed_edge(b, t, 1.0);
+    graph.add_directed_edge(s, c, 2.0);
+    graph.add_directed_edge(c, b, 2.0);
+    graph.add_directed_edge(a, d, 2.0);
+    graph.add_directed_edge(d, t, 2.0);
+
+    let limits = DistinctPathLimits {
+        max_overlap: 1.0,
+        max_stretch: 5.0,
+        ..DistinctPathLimits::default()
+    };
+    let result = distinct_paths(&graph, s, t, 4, limits, |x| *x, |_| true);
+    let last = result.last().unwrap();
+    assert_eq!(last.nodes, vec![s, c, b, a, d, t]);
+    assert!((last.overlap - 1.0 / 9.0).abs() < 1e-9);
+}
+
+#[test]
+fn test_find_distinct_routes() {
+    use learning_graph::{
+        command_result::CommandResult, modes::ModeRegistry, startup::parser::parse_line,
+    };
+
+    let processor = common::square().processor();
+    let run = |line| {
+        let command = parse_line(&ModeRegistry::default(), 1, line).unwrap();
+        processor.process(command).unwrap()
+    };
+
+    let result = run("FindDistinctRoutes Car 1 3 5 0.5 1.5");
+    let routes = match &result {
+        CommandResult::DistinctRoutes { routes, .. } => routes,
+        _ => panic!("expected routes, got {:?}", result),
+    };
+    let hops: Vec<usize> = routes.iter().map(|x| x.hops()).collect();
+    assert_eq!(hops, vec![1, 2, 2]);
+    assert!(routes.iter().all(|x| x.overlap == 0.0));
+    assert!(result
+        .to_string()
+        .starts_with("FindDistinctRoutes Car 1 3 5 0.5 1.5\n1,1,14.142,0.000,1 3\n"));
+
+    // The sides are more than 1.2 times the diagonal.
+    let result = run("FindDistinctRoutes Car 1 3 5 0.5 1.2");
+    assert_eq!(
+        result.to_string(),
+        "FindDistinctRoutes Car 1 3 5 0.5 1.2\n1,1,14.142,0.000,1 3"
+    );
+
+    for line in [
+        "FindDistinctRoutes Car 1 3 5 1.5 1.5",
+        "FindDistinctRoutes Car 1 3 5 0.5 0.5",
+        "FindDistinctRoutes Car 1 3 5 0.5",
+    ] {
+        assert!(parse_line(&ModeRegistry::default(), 1, line).is_err());
+    }
+}
@@ -0,0 +1,52 @@
+use learning_graph::{
+    models::{Command, TravelMode},
+    process_command::CommandProcessor,
+};
+
+extern crate learning_graph;
+
+mod common;
+
+/// A direct 10km foot path from 1 to 3, or a 20km detour by rail through 2.
+fn processor() -> CommandProcessor {
+    let mut network = common::Network::new();
+    network
+        .place(1, 0.0, 0.0)
+        .place(2, 0.0, 10000.0)
+        .place(3, 0.0, 20000.0)
+        .link(1, 2, "Rail")
+        .link(2, 3, "Rail")
+        .link(1, 3, "Foot");
+
+    network.processor()
+}
+
+fn main() {
+    let command = Command::FindFastestRoute(TravelMode::new("Foot"), 1, 3);
+    println!("{}", processor().process(command).unwrap());
+}
+
+#[test]
+fn test_fastest_route() {
+    use learning_graph::command_result::CommandResult;
+
+    let processor = processor();
+
+    let command = Command::FindFastestRoute(TravelMode::new("Foot"), 1, 3);
+    let result = processor.process(command).unwrap();
+
+    let legs = match &result {
+        CommandResult::FastestRoute { legs, .. } => legs,
+        _ => panic!("expected a route, got {:?}", result),
+    };
+    let ids: Vec<(i32, i32)> = legs.iter().map(|x| (x.from.id, x.to.id)).collect();
+    assert_eq!(ids, vec![(1, 2), (2, 3)]);
+    assert!(legs.iter().all(|x| x.mode == TravelMode::new("Rail")));
+
+    // 20km at the default rail speed of 90km/h.
+    let total = result.total_minutes().unwrap();
+    assert!((total - 20.0 / 90.0 * 60.0).abs() < 1e-9);
+
+    let command = Command::FindFastestRoute(TravelMode::new("Tram"), 1, 3);
+    assert!(processor.process(command).is_err());
+}
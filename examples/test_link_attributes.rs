@@ -0,0 +1,181 @@
+use learning_graph::{
+    modes::ModeRegistry,
+    startup::{deserialization::read_links, load_error::ErrorPolicy},
+};
+
+extern crate learning_graph;
+
+mod common;
+
+/// Rows giving every optional column, only some of them, or none at all.
+const LINKS: &str = "\
+1,2,Rail,25.5,12,3.40,400,true
+2,3,Rail,,30
+1,3,Car
+";
+
+fn main() {
+    let path = common::write_temp("learning_graph_links.csv", LINKS);
+    for (_, link) in read_links(&path, ErrorPolicy::CollectAll, &ModeRegistry::default()).unwrap() {
+        println!("{:?}", link);
+    }
+}
+
+#[test]
+fn test_link_attributes() {
+    let path = common::write_temp("learning_graph_test_links.csv", LINKS);
+    let (lines, links): (Vec<u64>, Vec<_>) =
+        read_links(&path, ErrorPolicy::CollectAll, &ModeRegistry::default())
+            .unwrap()
+            .into_iter()
+            .unzip();
+    assert_eq!(lines, vec![1, 2, 3]);
+
+    assert_eq!(links.len(), 3);
+    assert_eq!(links[0].length, Some(25.5));
+    assert_eq!(links[0].minutes, Some(12.0));
+    assert_eq!(links[0].cost, Some(3.4));
+    assert_eq!(links[0].capacity, Some(400));
+    assert!(links[0].is_one_way());
+
+    assert_eq!(links[1].length, None);
+    assert_eq!(links[1].minutes, Some(30.0));
+    assert!(!links[1].is_one_way());
+
+    assert_eq!(links[2].length, None);
+    assert_eq!(links[2].capacity, None);
+
+    let path = common::write_temp("learning_graph_test_bad_links.csv", "1,2,Rail,-4\n");
+    let errors = read_links(&path, ErrorPolicy::CollectAll, &ModeRegistry::default()).unwrap_err();
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].line, Some(1));
+}
+
+#[test]
+fn test_link_length_fallback() {
+    use learning_graph::{
+        models::{Command, Link, TravelMode},
+        process_error::ProcessError,
+    };
+
+    let mut network = common::Network::new();
+    network.place(1, 0.0, 0.0).place(2, 0.0, 10000.0);
+
+    let mut rail = Link::new(1, 2, TravelMode::new("Rail"));
+    rail.length = Some(12.5);
+    rail.one_way = Some(true);
+    network.add_link(rail).link(1, 2, "Foot");
+
+    let processor = network.processor();
+
+    // The rail link has a given length, the foot path falls back to the 10km between places.
+    let command = Command::FindShortestDistanceRoute(TravelMode::new("Rail"), 1, 2);
+    let result = processor.process(command).unwrap();
+    assert_eq!(result.total_distance(), Some(12.5));
+
+    let command = Command::FindShortestDistanceRoute(TravelMode::new("Foot"), 1, 2);
+    let result = processor.process(command).unwrap();
+    assert_eq!(result.total_distance(), Some(10.0));
+
+    // The rail link is one way, so there is no rail route back.
+    let command = Command::FindShortestDistanceRoute(TravelMode::new("Rail"), 2, 1);
+    let result = processor.process(command);
+    assert_eq!(result, Err(ProcessError::Disconnected { from: 2, to: 1 }));
+}
+
+#[test]
+fn test_route_takes_short_given_length() {
+    use learning_graph::{
+        command_result::CommandResult,
+        models::{Command, Link, TravelMode},
+    };
+
+    // 1 --- 2 --- 3 is the straight 20km to 3, while 1 -- 4 -- 3 goes out of the way
+    // but has given lengths of only 1km each, shorter than the distance to 3 from 4.
+    let mut network = common::Network::new();
+    network
+        .place(1, 0.0, 0.0)
+        .place(2, 0.0, 10000.0)
+        .place(3, 0.0, 20000.0)
+        .place(4, 20000.0, 10000.0)
+        .link(1, 2, "Rail")
+        .link(2, 3, "Rail");
+    for (start, end) in [(1, 4), (4, 3)] {
+        let mut link = Link::new(start, end, TravelMode::new("Rail"));
+        link.length = Some(1.0);
+        network.add_link(link);
+    }
+
+    let processor = network.processor();
+    let result = processor
+        .process(Command::FindRoute(TravelMode::new("Rail"), 1, 3))
+        .unwrap();
+    match result {
+        CommandResult::Route { route, .. } => {
+            let ids: Vec<i32> = route.iter().map(|x| x.id).collect();
+            assert_eq!(ids, vec![1, 4, 3]);
+        }
+        other => panic!("unexpected {:?}", other),
+    }
+}
+
+#[test]
+fn test_route_with_long_given_lengths() {
+    use learning_graph::{
+        command_result::CommandResult,
+        models::{Command, Link, TravelMode},
+    };
+
+    // 1 -- 2 -- 3 is the straight 20km to 3 but its links are given double their
+    // length, so the way round through 4, measured by distance, is shorter.
+    let mut network = common::Network::new();
+    network
+        .place(1, 0.0, 0.0)
+        .place(2, 0.0, 10000.0)
+        .place(3, 0.0, 20000.0)
+        .place(4, 10000.0, 10000.0)
+        .link(1, 4, "Rail")
+        .link(4, 3, "Rail");
+    for (start, end) in [(1, 2), (2, 3)] {
+        let mut link = Link::new(start, end, TravelMode::new("Rail"));
+        link.length = Some(20.0);
+        network.add_link(link);
+    }
+
+    let processor = network.processor();
+    let result = processor
+        .process(Command::FindRoute(TravelMode::new("Rail"), 1, 3))
+        .unwrap();
+    match result {
+        CommandResult::Route { route, .. } => {
+            let ids: Vec<i32> = route.iter().map(|x| x.id).collect();
+            assert_eq!(ids, vec![1, 4, 3]);
+        }
+        other => panic!("unexpected {:?}", other),
+    }
+}
+
+#[test]
+fn test_link_validate() {
+    use learning_graph::models::{Link, TravelMode};
+
+    let link = |length| {
+        let mut link = Link::new(1, 2, TravelMode::new("Rail"));
+        link.length = Some(length);
+        link.validate()
+    };
+
+    assert_eq!(link(0.0), Ok(()));
+    assert_eq!(
+        link(-4.0),
+        Err("link length must not be negative, got -4".to_string())
+    );
+    assert_eq!(
+        link(f64::NAN),
+        Err("link length must be a finite number, got NaN".to_string())
+    );
+    assert_eq!(
+        link(f64::INFINITY),
+        Err("link length must be a finite number, got inf".to_string())
+    );
+}
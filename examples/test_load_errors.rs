@@ -0,0 +1,64 @@
+use learning_graph::{
+    modes::ModeRegistry,
+    startup::{graph_builder::load, load_error::ErrorPolicy},
+};
+
+extern crate learning_graph;
+
+mod common;
+
+const PLACES: &str = "\
+Selby Rail,9361783,53.783,-1.063
+Howden Rail,11391765,53.765,-1.007
+Goole Rail,12681748,53.706,-0.875
+";
+
+/// A blank line, a link in an unknown mode, a dangling link and a self link.
+const LINKS: &str = "\
+9361783,11391765,Rail
+
+11391765,12681748,Tram
+11391765,99999999,Rail
+12681748,12681748,Rail
+";
+
+fn main() {
+    let places = common::write_temp("learning_graph_places.csv", PLACES);
+    let links = common::write_temp("learning_graph_bad_links.csv", LINKS);
+    let modes = ModeRegistry::default();
+
+    if let Err(errors) = load(&places, &links, ErrorPolicy::CollectAll, &modes, None) {
+        for error in errors {
+            println!("{}", error);
+        }
+    }
+}
+
+#[test]
+fn test_load_error_lines() {
+    use learning_graph::startup::load_error::LoadErrorReason;
+
+    let places = common::write_temp("learning_graph_test_places.csv", PLACES);
+    let links = common::write_temp("learning_graph_test_bad_links.csv", LINKS);
+    let modes = ModeRegistry::default();
+
+    let errors = load(&places, &links, ErrorPolicy::CollectAll, &modes, None).unwrap_err();
+    let found: Vec<(Option<u64>, LoadErrorReason)> =
+        errors.into_iter().map(|x| (x.line, x.reason)).collect();
+    assert_eq!(
+        found,
+        vec![
+            (
+                Some(3),
+                LoadErrorReason::UnknownTravelMode("Tram".to_string())
+            ),
+            (Some(4), LoadErrorReason::DanglingLink(99999999)),
+            (Some(5), LoadErrorReason::SelfLink(12681748)),
+        ]
+    );
+
+    // Only the first problem.
+    let errors = load(&places, &links, ErrorPolicy::FailFast, &modes, None).unwrap_err();
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].line, Some(3));
+}
@@ -0,0 +1,142 @@
+use learning_graph::{
+    algorithms::graph::Graph,
+    mode_view::ModeView,
+    models::{Link, Place, TravelMode},
+    modes::ModeRegistry,
+};
+
+extern crate learning_graph;
+
+mod common;
+
+fn network() -> Graph<Place, Link> {
+    let mut graph = Graph::new();
+
+    let a = graph.add_node(Place::new("a".to_string(), 1, 0.0, 0.0, 0.0, 0.0));
+    let b = graph.add_node(Place::new("b".to_string(), 2, 0.0, 0.0, 0.0, 1000.0));
+    let c = graph.add_node(Place::new("c".to_string(), 3, 0.0, 0.0, 0.0, 2000.0));
+
+    let link = |start, end, mode| Link::new(start, end, TravelMode::new(mode));
+    graph.add_edge(a, b, link(1, 2, "Rail"));
+    graph.add_edge(b, c, link(2, 3, "Ship"));
+    graph.add_edge(a, c, link(1, 3, "Foot"));
+
+    graph
+}
+
+fn main() {
+    use learning_graph::algorithms::visit::Neighbours;
+
+    let graph = network();
+    let modes = ModeRegistry::default();
+    let view = ModeView::new(&graph, &TravelMode::new("Bus"), &modes);
+
+    for edge in view.edges(1) {
+        println!("{:?}", edge);
+    }
+}
+
+#[test]
+fn test_mode_view() {
+    use learning_graph::algorithms::{dijkstra, graph::EdgeRef, visit::Neighbours};
+
+    let graph = network();
+    let modes = ModeRegistry::default();
+
+    let destinations = |mode| -> Vec<usize> {
+        let view = ModeView::new(&graph, &TravelMode::new(mode), &modes);
+        view.edges(0).map(|x| x.destination()).collect()
+    };
+
+    assert_eq!(destinations("Rail"), vec![1]);
+    assert_eq!(destinations("Foot"), vec![2, 1]);
+    assert!(destinations("Car").is_empty());
+    assert!(destinations("Tram").is_empty());
+
+    let frozen = graph.freeze();
+    let view = ModeView::new(&frozen, &TravelMode::new("Bike"), &modes);
+    let result = dijkstra(&view, 0, None, |_| 1, |_| true);
+    assert_eq!(result.path_to(2), Some(vec![0, 1, 2]));
+}
+
+#[test]
+fn test_custom_modes() {
+    use learning_graph::{
+        algorithms::{graph::EdgeRef, visit::Neighbours},
+        models::Command,
+        startup::parser::parse_line,
+    };
+
+    let graph = network();
+
+    let json = r##"[
+        { "name": "Foot", "links": ["Foot"], "speed": 5.0, "colour": "#772A53" },
+        { "name": "Rail", "links": ["Rail"], "speed": 90.0, "colour": "#073B74" },
+        { "name": "Ship", "links": ["Ship"], "speed": 30.0, "colour": "#9D7892" },
+        { "name": "Tram", "links": ["Rail", "Foot"], "speed": 25.0, "colour": "#FF0000" }
+    ]"##;
+    let modes: ModeRegistry = serde_json::from_str(json).unwrap();
+    assert_eq!(modes.validate(), Ok(()));
+
+    let view = ModeView::new(&graph, &TravelMode::new("Tram"), &modes);
+    let destinations: Vec<usize> = view.edges(0).map(|x| x.destination()).collect();
+    assert_eq!(destinations, vec![2, 1]);
+
+    match parse_line(&modes, 1, "FindRoute Tram 1 3") {
+        Ok(Command::FindRoute(mode, 1, 3)) => assert_eq!(mode, TravelMode::new("Tram")),
+        other => panic!("unexpected {:?}", other),
+    }
+    let diagnostic = parse_line(&modes, 1, "FindRoute Bus 1 3").unwrap_err();
+    assert_eq!(
+        (diagnostic.column, diagnostic.expected),
+        (11, "travel mode")
+    );
+
+    let undefined = serde_json::from_str::<ModeRegistry>(
+        r##"[{ "name": "Tram", "links": ["Cable-car"], "speed": 25.0, "colour": "red" }]"##,
+    );
+    assert!(undefined.is_err());
+}
+
+#[test]
+fn test_read_modes() {
+    use learning_graph::startup::{deserialization::read_modes, load_error::LoadErrorReason};
+
+    let modes = read_modes("Modes.json").unwrap();
+    assert_eq!(modes, ModeRegistry::default());
+    assert_eq!(modes.get("Bus").unwrap().speed, 40.0);
+    assert!(modes.get("Tram").is_none());
+
+    // The earlier format takes speeds and colours from the default modes.
+    let path = common::write_temp(
+        "learning_graph_links_modes.json",
+        r#"{ "Foot": ["Foot"], "Rail": ["Rail", "Foot"] }"#,
+    );
+    let modes = read_modes(&path).unwrap();
+    let rail = modes.get("Rail").unwrap();
+    assert_eq!((rail.speed, rail.colour.as_str()), (90.0, "#073B74"));
+    assert!(modes.can_traverse(&TravelMode::new("Rail"), &TravelMode::new("Foot")));
+    assert!(!modes.contains(&TravelMode::new("Bus")));
+
+    let invalid = |name: &str, contents: &str| {
+        let path = common::write_temp(name, contents);
+        match read_modes(&path).unwrap_err().reason {
+            LoadErrorReason::InvalidModes(message) => message,
+            other => panic!("unexpected {:?}", other),
+        }
+    };
+    assert_eq!(
+        invalid("learning_graph_tram_modes.json", r#"{ "Tram": ["Tram"] }"#),
+        "Tram is not a default mode, define it with a speed and colour in an array of modes"
+    );
+    assert_eq!(
+        invalid(
+            "learning_graph_twice_modes.json",
+            r##"[
+                { "name": "Rail", "links": ["Rail"], "speed": 90.0, "colour": "#073B74" },
+                { "name": "Rail", "links": ["Rail"], "speed": 80.0, "colour": "#073B74" }
+            ]"##
+        ),
+        "Rail is defined more than once"
+    );
+}
@@ -26,3 +26,28 @@ fn main() {
         println!("{:?}", i);
     }
 }
+
+#[test]
+fn test_directed_edges() {
+    use learning_graph::algorithms::{dijkstra, graph::EdgeRef};
+
+    let mut graph = Graph::new();
+
+    let node_a = graph.add_node("a");
+    let node_b = graph.add_node("b");
+    let node_c = graph.add_node("c");
+
+    graph.add_directed_edge(node_a, node_b, 1);
+    graph.add_edge(node_b, node_c, 1);
+
+    assert!(graph.is_adjacent(node_a, node_b));
+    assert!(!graph.is_adjacent(node_b, node_a));
+    assert!(graph.is_adjacent(node_c, node_b));
+
+    let incoming: Vec<_> = graph.incoming_edges(node_b).map(|x| x.source()).collect();
+    assert_eq!(incoming.len(), 2);
+    assert!(incoming.contains(&node_a) && incoming.contains(&node_c));
+
+    let from_c = dijkstra(&graph, node_c, None, |x| *x, |_| true);
+    assert!(!from_c.contains(node_a));
+}
@@ -0,0 +1,143 @@
+use learning_graph::{modes::ModeRegistry, startup::parser::parse_line};
+
+extern crate learning_graph;
+
+fn main() {
+    let modes = ModeRegistry::default();
+
+    for line in ["FindRoute Rail 1 2", "FindRoute Tram 1 2", "FindDist 1 2 3"] {
+        match parse_line(&modes, 1, line) {
+            Ok(command) => println!("{:?}", command),
+            Err(diagnostic) => println!("{}", diagnostic),
+        }
+    }
+}
+
+#[test]
+fn test_parse_each_command() {
+    use learning_graph::models::{Command, TravelMode};
+
+    let modes = ModeRegistry::default();
+    let rail = || TravelMode::new("Rail");
+
+    let cases = [
+        ("MaxDist", Command::MaxDist),
+        ("MaxLink", Command::MaxLink),
+        ("FindDist 1 2", Command::FindDist(1, 2)),
+        ("FindNeighbour 1", Command::FindNeighbour(1)),
+        ("Check Rail 1 2 3", Command::Check(rail(), vec![1, 2, 3])),
+        ("FindRoute Rail 1 2", Command::FindRoute(rail(), 1, 2)),
+        (
+            "FindShortestRoute Rail 1 2",
+            Command::FindShortestRoute(rail(), 1, 2),
+        ),
+        (
+            "FindShortestDistanceRoute Rail 1 2",
+            Command::FindShortestDistanceRoute(rail(), 1, 2),
+        ),
+        (
+            "FindFastestRoute Rail 1 2",
+            Command::FindFastestRoute(rail(), 1, 2),
+        ),
+        (
+            "FindRouteVia Rail 1 2 3",
+            Command::FindRouteVia(rail(), vec![1, 2, 3]),
+        ),
+        (
+            "FindAlternativeRoutes Rail 1 2 3",
+            Command::FindAlternativeRoutes(rail(), 1, 2, 3),
+        ),
+        (
+            "FindDistinctRoutes Rail 1 2 3 0.5 1.25",
+            Command::FindDistinctRoutes(rail(), 1, 2, 3, 0.5, 1.25),
+        ),
+        // Trailing whitespace and extra spaces between arguments are accepted.
+        ("FindDist 1   2  ", Command::FindDist(1, 2)),
+    ];
+
+    for (line, expected) in cases {
+        // A command is written back in the syntax it was parsed from.
+        assert_eq!(
+            parse_line(&modes, 1, &expected.to_string()),
+            Ok(expected.clone())
+        );
+        assert_eq!(parse_line(&modes, 1, line), Ok(expected), "{}", line);
+    }
+}
+
+#[test]
+fn test_parse_diagnostics() {
+    let modes = ModeRegistry::default();
+
+    let cases = [
+        ("Foo 1 2", 1, "command"),
+        ("", 1, "command"),
+        ("FindRoute Tram 1 2", 11, "travel mode"),
+        ("Check Railway 1 2", 7, "travel mode"),
+        ("FindDist 1 x", 12, "place id"),
+        ("FindRoute Rail 1", 17, "place id"),
+        ("FindDist", 9, "space"),
+        ("FindDist  1 2", 10, "place id"),
+        ("FindDist 1 2 3", 14, "end of line"),
+        ("MaxDistX", 8, "end of line"),
+        ("MaxLink 1", 9, "end of line"),
+        ("FindRouteVia Rail 1 2", 19, "via places"),
+        ("FindAlternativeRoutes Rail 1 2 0", 32, "route count"),
+        (
+            "FindDistinctRoutes Rail 1 2 3 1.5 2",
+            31,
+            "overlap fraction",
+        ),
+        (
+            "FindDistinctRoutes Rail 1 2 3 0.5 0.9",
+            35,
+            "stretch factor",
+        ),
+    ];
+
+    for (line, column, expected) in cases {
+        let diagnostic = parse_line(&modes, 4, line).unwrap_err();
+        assert_eq!(
+            (diagnostic.line, diagnostic.column, diagnostic.expected),
+            (4, column, expected),
+            "{}",
+            line
+        );
+    }
+
+    let diagnostic = parse_line(&modes, 4, "FindRoute Tram 1 2").unwrap_err();
+    assert_eq!(
+        diagnostic.to_string(),
+        "line 4, column 11: expected travel mode"
+    );
+}
+
+#[test]
+fn test_parse_commands_strictness() {
+    use learning_graph::{
+        models::Command,
+        startup::deserialization::{parse_commands, Strictness},
+    };
+
+    let modes = ModeRegistry::default();
+    let contents = "MaxDistX\n\nFindDist  1 2\nFindDist 1 2 junk\nFoo\nFindNeighbour 3\n";
+
+    // Bad lines are skipped and trailing input ignored, as the original reader did.
+    let commands = parse_commands(contents, Strictness::Lenient, &modes).unwrap();
+    assert_eq!(
+        commands,
+        vec![
+            Command::MaxDist,
+            Command::FindDist(1, 2),
+            Command::FindNeighbour(3)
+        ]
+    );
+
+    let diagnostics = parse_commands(contents, Strictness::Strict, &modes).unwrap_err();
+    let found: Vec<(usize, usize)> = diagnostics.iter().map(|x| (x.line, x.column)).collect();
+    assert_eq!(found, vec![(1, 8), (3, 10), (4, 14), (5, 1)]);
+
+    let contents = "MaxDist\n\nFindNeighbour 3\n";
+    let commands = parse_commands(contents, Strictness::Strict, &modes).unwrap();
+    assert_eq!(commands, vec![Command::MaxDist, Command::FindNeighbour(3)]);
+}
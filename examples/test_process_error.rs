@@ -0,0 +1,69 @@
+use learning_graph::models::{Command, TravelMode};
+
+extern crate learning_graph;
+
+mod common;
+
+fn main() {
+    let processor = common::square().processor();
+    let command = Command::FindRoute(TravelMode::new("Car"), 1, 9);
+
+    println!("{}", processor.process(command).unwrap_err());
+}
+
+#[test]
+fn test_process_errors() {
+    use learning_graph::process_error::ProcessError;
+
+    let processor = common::square().processor();
+    let car = TravelMode::new("Car");
+
+    let result = processor.process(Command::FindRoute(car.clone(), 1, 9));
+    assert_eq!(result, Err(ProcessError::UnknownPlace(9)));
+
+    let tram = TravelMode::new("Tram");
+    let result = processor.process(Command::FindRoute(tram.clone(), 1, 3));
+    assert_eq!(result, Err(ProcessError::UnknownTravelMode(tram)));
+
+    let result = processor.process(Command::Check(car.clone(), vec![]));
+    assert_eq!(result, Err(ProcessError::EmptyCheck));
+
+    // Places but no links between them.
+    let mut network = common::Network::new();
+    network.place(1, 0.0, 0.0).place(2, 0.0, 10000.0);
+    let processor = network.processor();
+
+    let result = processor.process(Command::MaxLink);
+    assert_eq!(result, Err(ProcessError::NoLinks));
+
+    let result = processor.process(Command::MaxDist);
+    assert_eq!(
+        result,
+        Err(ProcessError::TooFewPlaces {
+            count: 2,
+            required: 4
+        })
+    );
+
+    // Places with no link between them cannot be routed between.
+    let result = processor.process(Command::FindShortestDistanceRoute(car, 1, 2));
+    assert_eq!(result, Err(ProcessError::Disconnected { from: 1, to: 2 }));
+}
+
+#[test]
+fn test_error_text_output() {
+    use learning_graph::process_error::ProcessError;
+
+    let car = TravelMode::new("Car");
+
+    // An unreachable goal is written as the command followed by `Fail`, as it was
+    // before route commands reported it as an error.
+    let error = ProcessError::Disconnected { from: 1, to: 2 };
+    assert_eq!(error.to_string(), "no route from 1 to 2");
+    let command = Command::FindRoute(car.clone(), 1, 2);
+    assert_eq!(error.text_output(&command), "FindRoute Car 1 2 \nFail");
+
+    let error = ProcessError::UnknownPlace(9);
+    let command = Command::FindRoute(car, 1, 9);
+    assert_eq!(error.text_output(&command), "Error: unknown place id 9");
+}
@@ -0,0 +1,76 @@
+use learning_graph::projection::Projection;
+
+extern crate learning_graph;
+
+fn main() {
+    // York Rail and Zeebrugge Harbour lie in UTM zones 30 and 31.
+    let places = [(53.958, -1.093), (51.333, 3.207)];
+    let fitted = Projection::fitting(places.iter().copied());
+
+    for projection in [fitted, Projection::BritishNationalGrid] {
+        for (latitude, longitude) in places {
+            println!(
+                "{}: {:?}",
+                projection,
+                projection.project(latitude, longitude)
+            );
+        }
+    }
+}
+
+#[test]
+fn test_projection() {
+    use learning_graph::projection::utm_zone;
+
+    let close = |(e, n): (f64, f64), (expected_e, expected_n): (f64, f64), tolerance: f64| {
+        (e - expected_e).abs() < tolerance && (n - expected_n).abs() < tolerance
+    };
+
+    // The CN Tower is at 17T 630084.30 4833438.59.
+    let utm = Projection::Utm {
+        zone: 17,
+        south: false,
+    };
+    assert!(close(
+        utm.project(43.642567, -79.387139),
+        (630084.30, 4833438.59),
+        0.01
+    ));
+
+    // The central meridian of a zone is at 500km east, the equator at 10000km north
+    // in the southern hemisphere.
+    let south = Projection::Utm {
+        zone: 31,
+        south: true,
+    };
+    assert!(close(south.project(0.0, 3.0), (500000.0, 10000000.0), 1e-6));
+
+    // Caister Water Tower, TG 51409 13177, from the Ordnance Survey's guide to
+    // coordinate systems, given here by its WGS84 position. The Helmert transformation
+    // to OSGB36 is good to a few metres.
+    let bng = Projection::BritishNationalGrid;
+    assert!(close(
+        bng.project(52.657979, 1.716052),
+        (651409.9, 313177.3),
+        5.0
+    ));
+
+    assert_eq!(utm_zone(-1.093), 30);
+    assert_eq!(utm_zone(3.207), 31);
+    assert_eq!(utm_zone(180.0), 1);
+    assert_eq!(
+        Projection::fitting([(53.958, -1.093), (51.333, 3.207)]),
+        Projection::Utm {
+            zone: 31,
+            south: false
+        }
+    );
+    // Fiji lies either side of the antimeridian, in zones 60 and 1.
+    assert_eq!(
+        Projection::fitting([(-17.8, 179.0), (-16.5, -179.0)]),
+        Projection::Utm {
+            zone: 1,
+            south: true
+        }
+    );
+}
@@ -0,0 +1,63 @@
+use learning_graph::algorithms::graph::Graph;
+
+extern crate learning_graph;
+
+fn main() {
+    let mut graph = Graph::new();
+
+    let node_a = graph.add_node("a");
+    let node_b = graph.add_node("b");
+    let node_c = graph.add_node("c");
+
+    let edge_ab = graph.add_edge(node_a, node_b, 1);
+    graph.add_edge(node_b, node_c, 2);
+    graph.add_edge(node_c, node_a, 3);
+
+    graph.remove_edge(edge_ab);
+    graph.remove_node(node_b);
+
+    for node in 0..graph.raw_nodes().len() {
+        for edge in graph.edges(node) {
+            println!("{:?}", edge);
+        }
+    }
+}
+
+#[test]
+fn test_remove_edge_and_node() {
+    use learning_graph::algorithms::graph::{EdgeIndex, EdgeRef};
+
+    let mut graph = Graph::new();
+
+    let node_a = graph.add_node("a");
+    let node_b = graph.add_node("b");
+    let node_c = graph.add_node("c");
+    let node_d = graph.add_node("d");
+
+    let edge_ab = graph.add_edge(node_a, node_b, 1);
+    graph.add_edge(node_b, node_c, 2);
+    graph.add_edge(node_c, node_d, 3);
+    graph.add_edge(node_d, node_a, 4);
+
+    // The last edge, d -- a, moves into the removed edge's slot.
+    assert_eq!(graph.remove_edge(edge_ab), Some(1));
+    assert!(!graph.is_adjacent(node_a, node_b));
+    assert!(graph.is_adjacent(node_a, node_d));
+    assert_eq!(graph.raw_edges()[0].data, 4);
+
+    // The slot of the removed edge now holds d -- a, and no edge is past the end.
+    assert_eq!(graph.remove_edge(EdgeIndex::Index(3)), None);
+    assert_eq!(graph.remove_edge(EdgeIndex::None), None);
+    assert_eq!(graph.remove_edge(edge_ab), Some(4));
+    graph.add_edge(node_d, node_a, 4);
+
+    // The last node, d, moves into b's slot.
+    assert_eq!(graph.remove_node(node_b), Some("b"));
+    assert_eq!(graph.raw_nodes()[node_b].data, "d");
+    assert_eq!(graph.raw_edges().len(), 2);
+
+    let mut neighbours: Vec<_> = graph.edges(node_b).map(|x| x.destination()).collect();
+    neighbours.sort_unstable();
+    assert_eq!(neighbours, vec![node_a, node_c]);
+    assert!(graph.edges(node_c).all(|x| x.destination() == node_b));
+}
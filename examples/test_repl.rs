@@ -0,0 +1,77 @@
+use std::io::Cursor;
+
+use learning_graph::repl;
+
+extern crate learning_graph;
+
+mod common;
+
+/// Runs the repl over the common square with `input` typed in, returning what it wrote.
+fn session(input: &str) -> String {
+    let processor = common::square().processor();
+    let mut output = vec![];
+
+    repl::run(&processor, Cursor::new(input), &mut output).unwrap();
+    String::from_utf8(output).unwrap()
+}
+
+fn main() {
+    print!("{}", session("help\nFindDist 1 3\nhistory\nexit\n"));
+}
+
+#[test]
+fn test_repl_commands() {
+    let output = session(
+        "FindDist 1 3\n\
+         \n\
+         FindDist 1\n\
+         Foo 1\n\
+         FindRoute Car 1 9\n\
+         history\n\
+         exit\n\
+         FindDist 1 2\n",
+    );
+
+    assert_eq!(
+        output,
+        "> FindDist\n1,3,14.142\n\
+         > > Error: column 11: expected place id, usage: FindDist <place> <place>\n\
+         > Error: unknown command 'Foo', type 'help' to list commands\n\
+         > Error: unknown place id 9\n\
+         >    1  FindDist 1 3\n   2  FindDist 1\n   3  Foo 1\n   4  FindRoute Car 1 9\n\
+         > \n"
+    );
+
+    // `quit` also ends the session, and so does the end of the input.
+    assert_eq!(session("quit\nFindDist 1 2\n"), "> \n");
+    assert_eq!(session("  FindDist 1 3  "), "> FindDist\n1,3,14.142\n> \n");
+    assert_eq!(session(""), "> \n");
+}
+
+#[test]
+fn test_repl_help() {
+    use learning_graph::{modes::ModeRegistry, startup::parser::COMMAND_USAGE};
+
+    let output = session("help\n");
+    let lines: Vec<&str> = output.lines().collect();
+
+    assert_eq!(lines[0], "> Commands:");
+    assert_eq!(lines[1], "  MaxDist");
+    assert_eq!(lines[3], "  FindDist <place> <place>");
+    assert_eq!(lines.len(), COMMAND_USAGE.len() + 6);
+    assert_eq!(
+        lines[COMMAND_USAGE.len() + 1..COMMAND_USAGE.len() + 4],
+        ["  help", "  history", "  exit"]
+    );
+
+    let modes = ModeRegistry::default();
+    let names: Vec<&str> = modes.modes().iter().map(|x| x.name.name()).collect();
+    assert_eq!(
+        lines[COMMAND_USAGE.len() + 4],
+        format!("Modes: {}", names.join(", "))
+    );
+    assert_eq!(lines[COMMAND_USAGE.len() + 5], "> ");
+
+    // History is only of commands, not of help, history or blank lines.
+    assert_eq!(session("help\n\nhistory\n").lines().last(), Some("> > > "));
+}
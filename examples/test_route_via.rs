@@ -0,0 +1,70 @@
+use learning_graph::{
+    models::{Command, TravelMode},
+    process_command::CommandProcessor,
+};
+
+extern crate learning_graph;
+
+mod common;
+
+/// The common square by car, and a fifth place with no links.
+fn processor() -> CommandProcessor {
+    let mut network = common::square();
+    network.place(5, 50000.0, 50000.0);
+    network.processor()
+}
+
+fn main() {
+    let command = Command::FindRouteVia(TravelMode::new("Car"), vec![2, 4, 3]);
+
+    println!("{}", processor().process(command).unwrap());
+}
+
+#[test]
+fn test_find_route_via() {
+    use learning_graph::{
+        command_result::CommandResult, modes::ModeRegistry, startup::parser::parse_line,
+    };
+
+    let processor = processor();
+    let run = |line| {
+        let command = parse_line(&ModeRegistry::default(), 1, line).unwrap();
+        processor.process(command).unwrap()
+    };
+
+    // 2 to 4 takes two sides, either way round, then one more side to 3.
+    let result = run("FindRouteVia Car 2 4 3");
+    match &result {
+        CommandResult::RouteVia {
+            segments, route, ..
+        } => {
+            assert_eq!(segments.len(), 2);
+            assert!((segments[0].distance().unwrap() - 20.0).abs() < 1e-9);
+            assert!((segments[1].distance().unwrap() - 10.0).abs() < 1e-9);
+
+            let ids: Vec<i32> = route.as_ref().unwrap().iter().map(|x| x.id).collect();
+            assert_eq!(ids.len(), 4);
+            assert_eq!((ids[0], ids[2], ids[3]), (2, 4, 3));
+        }
+        _ => panic!("expected a route, got {:?}", result),
+    }
+    assert!(result
+        .to_string()
+        .contains("\n4,3,10.000,4 3\nTotal,30.000,2 "));
+
+    // A place visited twice in a row is a segment with no legs.
+    assert_eq!(
+        run("FindRouteVia Car 1 1 3").to_string(),
+        "FindRouteVia Car 1 1 3\n1,1,0.000,1\n1,3,14.142,1 3\nTotal,14.142,1 3"
+    );
+
+    // Every segment is reported, including those after the unreachable one.
+    assert_eq!(
+        run("FindRouteVia Car 1 5 3 4").to_string(),
+        "FindRouteVia Car 1 5 3 4\n1,5,FAIL\n5,3,FAIL\n3,4,10.000,3 4\nFail"
+    );
+
+    assert!(parse_line(&ModeRegistry::default(), 1, "FindRouteVia Car 1 3").is_err());
+    let command = Command::FindRouteVia(TravelMode::new("Car"), vec![1, 6, 3]);
+    assert!(processor.process(command).is_err());
+}
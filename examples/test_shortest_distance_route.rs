@@ -0,0 +1,63 @@
+use learning_graph::{
+    models::{Command, TravelMode},
+    process_command::CommandProcessor,
+};
+
+extern crate learning_graph;
+
+mod common;
+
+/// A 3km and a 4km link from 1 to 3 through 2, a longer detour through 4, and a
+/// fifth place with no links.
+fn processor() -> CommandProcessor {
+    let mut network = common::Network::new();
+    network
+        .place(1, 0.0, 0.0)
+        .place(2, 0.0, 3000.0)
+        .place(3, 4000.0, 3000.0)
+        .place(4, 10000.0, 0.0)
+        .place(5, 50000.0, 50000.0)
+        .link(1, 2, "Car")
+        .link(2, 3, "Car")
+        .link(1, 4, "Car")
+        .link(4, 3, "Car");
+
+    network.processor()
+}
+
+fn main() {
+    let command = Command::FindShortestDistanceRoute(TravelMode::new("Car"), 1, 3);
+    println!("{}", processor().process(command).unwrap());
+}
+
+#[test]
+fn test_shortest_distance_route() {
+    use learning_graph::{command_result::CommandResult, process_error::ProcessError};
+
+    let processor = processor();
+
+    let command = Command::FindShortestDistanceRoute(TravelMode::new("Car"), 1, 3);
+    let result = processor.process(command).unwrap();
+
+    let legs = match &result {
+        CommandResult::DistanceRoute { legs, .. } => legs,
+        _ => panic!("expected a route, got {:?}", result),
+    };
+    let ids: Vec<(i32, i32)> = legs.iter().map(|x| (x.from.id, x.to.id)).collect();
+    assert_eq!(ids, vec![(1, 2), (2, 3)]);
+    assert!((legs[0].distance - 3.0).abs() < 1e-9);
+    assert!((legs[1].distance - 4.0).abs() < 1e-9);
+    assert!((result.total_distance().unwrap() - 7.0).abs() < 1e-9);
+    assert_eq!(
+        result.to_string(),
+        "FindShortestDistanceRoute Car 1 3\n1,2,3.000\n2,3,4.000\nTotal,7.000"
+    );
+
+    let command = Command::FindShortestDistanceRoute(TravelMode::new("Car"), 1, 5);
+    let error = processor.process(command.clone()).unwrap_err();
+    assert_eq!(error, ProcessError::Disconnected { from: 1, to: 5 });
+    assert_eq!(
+        error.text_output(&command),
+        "FindShortestDistanceRoute Car 1 5 \nFail"
+    );
+}
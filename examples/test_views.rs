@@ -0,0 +1,63 @@
+use learning_graph::algorithms::{
+    dijkstra,
+    graph::{EdgeRef, Graph},
+    views::{EdgeFiltered, Reversed},
+};
+
+extern crate learning_graph;
+
+fn main() {
+    let mut graph = Graph::new();
+
+    let node_a = graph.add_node("a");
+    let node_b = graph.add_node("b");
+    let node_c = graph.add_node("c");
+
+    graph.add_directed_edge(node_a, node_b, 1);
+    graph.add_directed_edge(node_b, node_c, 2);
+    graph.add_edge(node_a, node_c, 5);
+
+    let cheap = EdgeFiltered::new(&graph, |x| *x.weight() < 5);
+    println!("{:?}", dijkstra(&cheap, node_a, None, |x| *x, |_| true));
+
+    let reversed = Reversed(&graph);
+    println!("{:?}", dijkstra(&reversed, node_c, None, |x| *x, |_| true));
+}
+
+#[test]
+fn test_views() {
+    use learning_graph::algorithms::visit::Neighbours;
+
+    let mut graph = Graph::new();
+
+    let node_a = graph.add_node("a");
+    let node_b = graph.add_node("b");
+    let node_c = graph.add_node("c");
+
+    graph.add_directed_edge(node_a, node_b, 1);
+    graph.add_directed_edge(node_b, node_c, 2);
+    graph.add_edge(node_a, node_c, 5);
+
+    let cheap = EdgeFiltered::new(&graph, |x| *x.weight() < 5);
+    let result = dijkstra(&cheap, node_c, None, |x| *x, |_| true);
+    assert_eq!(result.len(), 1);
+
+    let result = dijkstra(&cheap, node_a, None, |x| *x, |_| true);
+    assert_eq!(result.cost_to(node_c), Some(3));
+    assert_eq!(result.predecessor(node_c), Some(node_b));
+
+    let reversed = Reversed(&graph);
+    let result = dijkstra(&reversed, node_c, None, |x| *x, |_| true);
+    assert_eq!(result.path_to(node_a), Some(vec![node_c, node_b, node_a]));
+    assert_eq!(result.cost_to(node_a), Some(3));
+
+    let edges: Vec<_> = reversed.edges(node_b).map(|x| x.nodes).collect();
+    assert_eq!(edges, vec![[node_b, node_a]]);
+
+    let frozen = graph.freeze();
+    let reversed = Reversed(&frozen);
+    let result = dijkstra(&reversed, node_b, None, |x| *x, |_| true);
+    assert_eq!(result.cost_to(node_a), Some(1));
+    assert_eq!(result.path_to(node_c), Some(vec![node_b, node_a, node_c]));
+    assert_eq!(result.cost_to(node_c), Some(6));
+}
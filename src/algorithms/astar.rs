@@ -0,0 +1,96 @@
+use std::collections::hash_map::Entry;
+use std::collections::{BinaryHeap, HashMap, HashSet};
+use std::ops::Add;
+
+use ordered_float::OrderedFloat;
+
+use super::dijkstra::MinScored;
+use super::graph::{EdgeRef, NodeIndex};
+use super::shortest_path_tree::ShortestPathTree;
+use super::visit::{Neighbours, NodeWeights};
+use crate::distance::DistanceModel;
+use crate::models::Place;
+
+/// A* search from `start` to `goal`.
+///
+/// `get_cost` gives the cost of traversing an edge and `heuristic` estimates the
+/// remaining cost from a node to `goal`. The heuristic must never overestimate
+/// and must be consistent, otherwise the returned route may not be optimal.
+///
+/// Returns the tree of nodes reached, the same as `dijkstra`.
+pub fn astar<G, T, S, H, C>(
+    graph: &G,
+    start: NodeIndex,
+    goal: NodeIndex,
+    get_cost: S,
+    heuristic: H,
+    traversable: T,
+) -> ShortestPathTree<C>
+where
+    G: Neighbours + NodeWeights,
+    S: Fn(&G::EdgeWeight) -> C,
+    H: Fn(&G::NodeWeight) -> C,
+    T: Fn(&G::EdgeWeight) -> bool,
+    C: Default + Ord + Add<C, Output = C> + Copy,
+{
+    let mut unvisited_queue = BinaryHeap::new();
+    let mut visited = HashSet::new();
+    let mut scores = HashMap::new();
+
+    let start_node = match graph.node_weight(start) {
+        Some(node) => node,
+        None => return ShortestPathTree::new(start, scores),
+    };
+
+    unvisited_queue.push(MinScored(heuristic(start_node), start));
+    scores.insert(start, (C::default(), start));
+
+    while let Some(MinScored(_, node_id)) = unvisited_queue.pop() {
+        if node_id == goal {
+            break;
+        }
+        if !visited.insert(node_id) {
+            continue;
+        }
+
+        let score = scores[&node_id].0;
+
+        for edge_ref in graph
+            .edges(node_id)
+            .filter(|x| traversable(x.data))
+            .filter(|edge_ref| !visited.contains(&edge_ref.destination()))
+        {
+            let dest_id = edge_ref.destination();
+            let total_cost = score + get_cost(edge_ref.data);
+
+            match scores.entry(dest_id) {
+                Entry::Occupied(mut entry) => {
+                    if total_cost >= entry.get().0 {
+                        continue;
+                    }
+                    entry.insert((total_cost, node_id));
+                }
+                Entry::Vacant(entry) => {
+                    entry.insert((total_cost, node_id));
+                }
+            }
+
+            let dest_node = graph.node_weight(dest_id).unwrap();
+            let estimate = total_cost + heuristic(dest_node);
+            unvisited_queue.push(MinScored(estimate, dest_id));
+        }
+    }
+
+    ShortestPathTree::new(start, scores)
+}
+
+/// Distance in kilometres from a place to `goal`, measured by `distance_model`.
+///
+/// Admissible whenever edge costs are at least the distance between the places of
+/// the link, which holds for links measured by the same model.
+pub fn straight_line_heuristic(
+    goal: &Place,
+    distance_model: DistanceModel,
+) -> impl Fn(&Place) -> OrderedFloat<f64> + '_ {
+    move |place| OrderedFloat(distance_model.distance(place, goal))
+}
@@ -0,0 +1,150 @@
+use std::collections::{BinaryHeap, HashMap, HashSet};
+use std::ops::Add;
+
+use super::dijkstra::MinScored;
+use super::graph::{EdgeRef, NodeIndex};
+use super::shortest_path_tree::ShortestPathTree;
+use super::views::Reversed;
+use super::visit::{IncomingNeighbours, Neighbours};
+
+/// Dijkstra's algorithm from `start` to `goal`, searching forward from `start` and
+/// backward from `goal` at the same time until the two searches meet.
+///
+/// Each search settles roughly the places within half the route's cost of its
+/// end, so far fewer places are visited than by `dijkstra` on long routes.
+///
+/// Returns the cost of the cheapest route and the nodes along it from `start` to
+/// `goal`, or `None` when `goal` cannot be reached.
+pub fn bidirectional_dijkstra<G, T, S, C>(
+    graph: &G,
+    start: NodeIndex,
+    goal: NodeIndex,
+    get_cost: S,
+    traversable: T,
+) -> Option<(C, Vec<NodeIndex>)>
+where
+    G: IncomingNeighbours,
+    S: Fn(&G::EdgeWeight) -> C,
+    T: Fn(&G::EdgeWeight) -> bool,
+    C: Default + Ord + Add<C, Output = C> + Copy,
+{
+    if start == goal {
+        return Some((C::default(), vec![start]));
+    }
+
+    let reversed = Reversed(graph);
+    let mut forward = Search::new(start);
+    let mut backward = Search::new(goal);
+    // The cheapest complete route found so far, by the node where the searches met.
+    let mut best: Option<(C, NodeIndex)> = None;
+
+    while let (Some(next_forward), Some(next_backward)) = (forward.peek(), backward.peek()) {
+        // Every route not yet found costs at least as much as the two frontiers together.
+        if best.is_some_and(|(cost, _)| next_forward + next_backward >= cost) {
+            break;
+        }
+
+        if next_forward <= next_backward {
+            forward.settle_next(graph, &get_cost, &traversable, &backward, &mut best);
+        } else {
+            backward.settle_next(&reversed, &get_cost, &traversable, &forward, &mut best);
+        }
+    }
+
+    let (cost, meeting) = best?;
+
+    // Both searches reached the meeting node, the backward one along reversed edges.
+    let mut path = ShortestPathTree::new(start, forward.scores)
+        .path_to(meeting)
+        .unwrap();
+    let mut to_goal = ShortestPathTree::new(goal, backward.scores)
+        .path_to(meeting)
+        .unwrap();
+    to_goal.reverse();
+    path.extend(to_goal.into_iter().skip(1));
+
+    Some((cost, path))
+}
+
+/// One direction of a bidirectional search.
+struct Search<C> {
+    queue: BinaryHeap<MinScored<C, NodeIndex>>,
+    visited: HashSet<NodeIndex>,
+    /// The best known `(cost, previous node)` of every node reached.
+    scores: HashMap<NodeIndex, (C, NodeIndex)>,
+}
+
+impl<C> Search<C>
+where
+    C: Default + Ord + Add<C, Output = C> + Copy,
+{
+    fn new(origin: NodeIndex) -> Self {
+        let mut queue = BinaryHeap::new();
+        queue.push(MinScored(C::default(), origin));
+
+        let mut scores = HashMap::new();
+        scores.insert(origin, (C::default(), origin));
+
+        Self {
+            queue,
+            visited: HashSet::new(),
+            scores,
+        }
+    }
+
+    /// The lowest cost in the queue, or `None` when the search is exhausted.
+    fn peek(&self) -> Option<C> {
+        self.queue.peek().map(|x| x.0)
+    }
+
+    /// Settles the cheapest queued node, relaxing its edges in `graph` and
+    /// recording in `best` any cheaper route through a node `other` has reached.
+    fn settle_next<G, S, T>(
+        &mut self,
+        graph: &G,
+        get_cost: &S,
+        traversable: &T,
+        other: &Search<C>,
+        best: &mut Option<(C, NodeIndex)>,
+    ) where
+        G: Neighbours,
+        S: Fn(&G::EdgeWeight) -> C,
+        T: Fn(&G::EdgeWeight) -> bool,
+    {
+        let MinScored(score, node_id) = match self.queue.pop() {
+            Some(next) => next,
+            None => return,
+        };
+        if !self.visited.insert(node_id) {
+            return;
+        }
+
+        let visited = &self.visited;
+        for edge_ref in graph
+            .edges(node_id)
+            .filter(|x| traversable(x.data))
+            .filter(|edge_ref| !visited.contains(&edge_ref.destination()))
+        {
+            let dest_id = edge_ref.destination();
+            let total_cost = score + get_cost(edge_ref.data);
+
+            let improved = self
+                .scores
+                .get(&dest_id)
+                .is_none_or(|(cost, _)| total_cost < *cost);
+            if !improved {
+                continue;
+            }
+
+            self.scores.insert(dest_id, (total_cost, node_id));
+            self.queue.push(MinScored(total_cost, dest_id));
+
+            if let Some((other_cost, _)) = other.scores.get(&dest_id) {
+                let route_cost = total_cost + *other_cost;
+                if best.is_none_or(|(cost, _)| route_cost < cost) {
+                    *best = Some((route_cost, dest_id));
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,162 @@
+use std::slice::Iter;
+
+use super::graph::{EdgeIndex, EdgeReference, EdgeType, Graph, NodeIndex};
+
+/// An edge of a `FrozenGraph`, stored in the same order as in the `Graph` it came from.
+#[derive(Clone, Copy, Debug)]
+pub struct FrozenEdge<E> {
+    pub data: E,
+
+    pub source: NodeIndex,
+    pub destination: NodeIndex,
+    pub directed: bool,
+}
+
+/// Immutable compressed sparse row (CSR) form of a `Graph`.
+///
+/// The edges each node can traverse are stored contiguously, so iterating them walks
+/// a slice instead of following the linked lists of `Graph`. Node and edge indices
+/// are the same as in the graph that was frozen.
+#[derive(Clone, Debug)]
+pub struct FrozenGraph<N, E> {
+    nodes: Vec<N>,
+    edges: Vec<FrozenEdge<E>>,
+
+    outgoing: Adjacency,
+    incoming: Adjacency,
+}
+
+/// Per-node ranges into `targets`, where each target is `(other node, edge index)`.
+#[derive(Clone, Debug)]
+struct Adjacency {
+    offsets: Vec<usize>,
+    targets: Vec<(NodeIndex, usize)>,
+}
+
+impl<N: Clone, E: Clone> Graph<N, E> {
+    /// Copies the graph into a `FrozenGraph` for fast read-only queries.
+    pub fn freeze(&self) -> FrozenGraph<N, E> {
+        let nodes: Vec<N> = self.raw_nodes().iter().map(|x| x.data.clone()).collect();
+        let edges = self
+            .raw_edges()
+            .iter()
+            .map(|x| FrozenEdge {
+                data: x.data.clone(),
+                source: x.source,
+                destination: x.destination,
+                directed: x.directed,
+            })
+            .collect();
+
+        let outgoing = Adjacency::new(self, EdgeType::Outgoing);
+        let incoming = Adjacency::new(self, EdgeType::Incoming);
+
+        FrozenGraph {
+            nodes,
+            edges,
+            outgoing,
+            incoming,
+        }
+    }
+}
+
+impl Adjacency {
+    fn new<N, E>(graph: &Graph<N, E>, direction: EdgeType) -> Self {
+        let mut offsets = Vec::with_capacity(graph.raw_nodes().len() + 1);
+        let mut targets = Vec::with_capacity(graph.raw_edges().len() * 2);
+
+        offsets.push(0);
+        for node in 0..graph.raw_nodes().len() {
+            for edge in graph.edges_directed(node, direction) {
+                let other = match direction {
+                    EdgeType::Outgoing => edge.nodes[1],
+                    EdgeType::Incoming => edge.nodes[0],
+                };
+                targets.push((other, edge.index.unwrap()));
+            }
+            offsets.push(targets.len());
+        }
+
+        Self { offsets, targets }
+    }
+
+    fn get(&self, node: NodeIndex) -> &[(NodeIndex, usize)] {
+        match (self.offsets.get(node), self.offsets.get(node + 1)) {
+            (Some(start), Some(end)) => &self.targets[*start..*end],
+            _ => &[],
+        }
+    }
+}
+
+impl<N, E> FrozenGraph<N, E> {
+    pub fn get_node(&self, index: NodeIndex) -> Option<&N> {
+        self.nodes.get(index)
+    }
+
+    pub fn raw_nodes(&self) -> &[N] {
+        &self.nodes
+    }
+
+    pub fn raw_edges(&self) -> &[FrozenEdge<E>] {
+        &self.edges
+    }
+
+    /// Edges that can be traversed leaving `node_index`, with `node_index` as their source.
+    pub fn edges(&self, node_index: NodeIndex) -> FrozenEdges<'_, E> {
+        self.edges_directed(node_index, EdgeType::Outgoing)
+    }
+
+    /// Edges that can be traversed arriving at `node_index`, with `node_index` as their
+    /// destination.
+    pub fn incoming_edges(&self, node_index: NodeIndex) -> FrozenEdges<'_, E> {
+        self.edges_directed(node_index, EdgeType::Incoming)
+    }
+
+    pub fn edges_directed(&self, node_index: NodeIndex, direction: EdgeType) -> FrozenEdges<'_, E> {
+        let adjacency = match direction {
+            EdgeType::Outgoing => &self.outgoing,
+            EdgeType::Incoming => &self.incoming,
+        };
+
+        FrozenEdges {
+            edges: &self.edges,
+            node: node_index,
+            direction,
+            targets: adjacency.get(node_index).iter(),
+        }
+    }
+
+    pub fn is_adjacent(&self, a: NodeIndex, b: NodeIndex) -> bool {
+        self.outgoing.get(a).iter().any(|(other, _)| *other == b)
+    }
+}
+
+pub struct FrozenEdges<'a, E> {
+    edges: &'a [FrozenEdge<E>],
+    node: NodeIndex,
+    direction: EdgeType,
+    targets: Iter<'a, (NodeIndex, usize)>,
+}
+
+impl<'a, E> Iterator for FrozenEdges<'a, E> {
+    type Item = EdgeReference<'a, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (other, index) = *self.targets.next()?;
+
+        let nodes = match self.direction {
+            EdgeType::Outgoing => [self.node, other],
+            EdgeType::Incoming => [other, self.node],
+        };
+
+        Some(EdgeReference {
+            index: EdgeIndex::Index(index),
+            nodes,
+            data: &self.edges[index].data,
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.targets.size_hint()
+    }
+}
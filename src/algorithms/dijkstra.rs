@@ -1,16 +1,20 @@
 use std::collections::{BinaryHeap, HashMap, HashSet};
 use std::ops::Add;
 
-pub fn dijkstra<N, E, T, S, C>(
-    graph: &Graph<N, E>,
+use super::shortest_path_tree::ShortestPathTree;
+
+/// Dijkstra's algorithm from `start`, stopping once `goal` is reached when one is given.
+pub fn dijkstra<G, T, S, C>(
+    graph: &G,
     start: NodeIndex,
     goal: Option<NodeIndex>,
     get_cost: S,
     traversable: T,
-) -> HashMap<NodeIndex, (C, NodeIndex)>
+) -> ShortestPathTree<C>
 where
-    S: Fn(&E) -> C,
-    T: Fn(&E) -> bool,
+    G: Neighbours,
+    S: Fn(&G::EdgeWeight) -> C,
+    T: Fn(&G::EdgeWeight) -> bool,
     C: Default + Ord + PartialOrd + Add<C, Output = C> + Default + Clone + Copy,
 {
     let mut unvisited_queue = BinaryHeap::new();
@@ -57,11 +61,12 @@ where
         }
     }
 
-    scores
+    ShortestPathTree::new(start, scores)
 }
 use std::cmp::Ordering;
 
-use super::graph::{EdgeRef, Graph, NodeIndex};
+use super::graph::{EdgeRef, NodeIndex};
+use super::visit::Neighbours;
 
 /// `MinScored<K, T>` holds a score `K` and a scored object `T` in
 /// a pair for use with a `BinaryHeap`.
@@ -0,0 +1,212 @@
+use std::collections::{BinaryHeap, HashMap, HashSet};
+
+use ordered_float::OrderedFloat;
+
+use super::dijkstra::MinScored;
+use super::graph::{EdgeRef, NodeIndex};
+use super::shortest_path_tree::ShortestPathTree;
+use super::visit::Neighbours;
+
+/// How different the routes of `distinct_paths` must be from the cheapest route.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DistinctPathLimits {
+    /// The largest fraction of a route's cost that may be shared with the cheapest route.
+    pub max_overlap: f64,
+    /// The largest cost of a route as a multiple of the cheapest route's cost.
+    pub max_stretch: f64,
+    /// The fraction added to the cost of a step each time a search takes it.
+    pub penalty: f64,
+}
+
+impl Default for DistinctPathLimits {
+    fn default() -> Self {
+        Self {
+            max_overlap: 0.5,
+            max_stretch: 1.5,
+            penalty: 0.5,
+        }
+    }
+}
+
+/// A route found by `distinct_paths`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DistinctPath {
+    pub cost: f64,
+    pub nodes: Vec<NodeIndex>,
+    /// The fraction of the cost shared with the cheapest route.
+    pub overlap: f64,
+}
+
+/// The number of searches made for each route asked for before giving up.
+const SEARCHES_PER_PATH: usize = 5;
+
+/// Up to `count` routes from `start` to `goal` that are genuinely different, cheapest
+/// first, found by the penalty method.
+///
+/// The first route is the cheapest. Every search after it makes the steps of the
+/// routes already searched more expensive by `limits.penalty` for each time they were
+/// taken, pushing the next search onto other links. A route found this way is kept
+/// when it shares at most `limits.max_overlap` of its cost with the cheapest route and
+/// costs at most `limits.max_stretch` times the cheapest route. A step is shared when
+/// the cheapest route travels between the same two nodes in either direction.
+///
+/// Costs are compared without penalties. `get_cost` and `traversable` are used as
+/// in `dijkstra`.
+pub fn distinct_paths<G, T, S>(
+    graph: &G,
+    start: NodeIndex,
+    goal: NodeIndex,
+    count: usize,
+    limits: DistinctPathLimits,
+    get_cost: S,
+    traversable: T,
+) -> Vec<DistinctPath>
+where
+    G: Neighbours,
+    S: Fn(&G::EdgeWeight) -> f64,
+    T: Fn(&G::EdgeWeight) -> bool,
+{
+    let mut found: Vec<DistinctPath> = vec![];
+    let mut best_steps: HashSet<(NodeIndex, NodeIndex)> = HashSet::new();
+    let mut uses: HashMap<(NodeIndex, NodeIndex), i32> = HashMap::new();
+    let mut searched: HashSet<Vec<NodeIndex>> = HashSet::new();
+
+    for _ in 0..count * SEARCHES_PER_PATH {
+        if found.len() == count {
+            break;
+        }
+
+        let penalised = |source, destination, weight: &G::EdgeWeight| {
+            let times = uses.get(&(source, destination)).copied().unwrap_or(0);
+            get_cost(weight) * (1.0 + limits.penalty).powi(times)
+        };
+        let nodes = match cheapest_path(graph, start, goal, penalised, &traversable) {
+            Some(nodes) => nodes,
+            None => break,
+        };
+
+        let step_costs: Vec<f64> = nodes
+            .windows(2)
+            .map(|x| step_cost(graph, x[0], x[1], &get_cost, &traversable))
+            .collect();
+        for step in nodes.windows(2) {
+            *uses.entry((step[0], step[1])).or_default() += 1;
+        }
+
+        // A penalty too small to move the search finds the same route again.
+        if !searched.insert(nodes.clone()) {
+            continue;
+        }
+
+        let cost: f64 = step_costs.iter().sum();
+        if let Some(best) = found.first() {
+            if cost > best.cost * limits.max_stretch {
+                continue;
+            }
+        }
+
+        // Folded from 0.0 as an empty `sum` of floats is -0.0.
+        let shared = nodes
+            .windows(2)
+            .zip(&step_costs)
+            .filter(|(step, _)| best_steps.contains(&undirected(step[0], step[1])))
+            .fold(0.0, |total, (_, cost)| total + cost);
+        let overlap = if found.is_empty() {
+            0.0
+        } else if cost > 0.0 {
+            shared / cost
+        } else {
+            1.0
+        };
+        if overlap > limits.max_overlap {
+            continue;
+        }
+
+        if found.is_empty() {
+            best_steps = nodes.windows(2).map(|x| undirected(x[0], x[1])).collect();
+        }
+        found.push(DistinctPath {
+            cost,
+            nodes,
+            overlap,
+        });
+    }
+
+    found.sort_by_key(|x| OrderedFloat(x.cost));
+    found
+}
+
+/// A step between two nodes as the same pair whichever way it is travelled.
+fn undirected(a: NodeIndex, b: NodeIndex) -> (NodeIndex, NodeIndex) {
+    (a.min(b), a.max(b))
+}
+
+/// The cost of the cheapest traversable edge from `source` to `destination`.
+fn step_cost<G, S, T>(
+    graph: &G,
+    source: NodeIndex,
+    destination: NodeIndex,
+    get_cost: &S,
+    traversable: &T,
+) -> f64
+where
+    G: Neighbours,
+    S: Fn(&G::EdgeWeight) -> f64,
+    T: Fn(&G::EdgeWeight) -> bool,
+{
+    graph
+        .edges(source)
+        .filter(|x| x.destination() == destination && traversable(x.data))
+        .map(|x| OrderedFloat(get_cost(x.data)))
+        .min()
+        .map_or(0.0, |x| x.into_inner())
+}
+
+/// Dijkstra's algorithm where the cost of an edge also depends on its end points.
+fn cheapest_path<G, T, S>(
+    graph: &G,
+    start: NodeIndex,
+    goal: NodeIndex,
+    get_cost: S,
+    traversable: &T,
+) -> Option<Vec<NodeIndex>>
+where
+    G: Neighbours,
+    S: Fn(NodeIndex, NodeIndex, &G::EdgeWeight) -> f64,
+    T: Fn(&G::EdgeWeight) -> bool,
+{
+    let mut unvisited_queue = BinaryHeap::new();
+    let mut visited = HashSet::new();
+    let mut scores = HashMap::new();
+
+    unvisited_queue.push(MinScored(OrderedFloat(0.0), start));
+    scores.insert(start, (OrderedFloat(0.0), start));
+
+    while let Some(MinScored(score, node_id)) = unvisited_queue.pop() {
+        if node_id == goal {
+            break;
+        }
+        if !visited.insert(node_id) {
+            continue;
+        }
+
+        for edge_ref in graph
+            .edges(node_id)
+            .filter(|x| traversable(x.data))
+            .filter(|edge_ref| !visited.contains(&edge_ref.destination()))
+        {
+            let dest_id = edge_ref.destination();
+            let total_cost = score + OrderedFloat(get_cost(node_id, dest_id, edge_ref.data));
+
+            if scores
+                .get(&dest_id)
+                .is_none_or(|(cost, _)| total_cost < *cost)
+            {
+                scores.insert(dest_id, (total_cost, node_id));
+                unvisited_queue.push(MinScored(total_cost, dest_id));
+            }
+        }
+    }
+
+    ShortestPathTree::new(start, scores).path_to(goal)
+}
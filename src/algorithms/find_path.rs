@@ -2,16 +2,17 @@ use std::collections::{BinaryHeap, HashMap, HashSet};
 
 // use crate::graph::{EdgeRef, Graph, NodeIndex};
 
-pub fn find_path<N, E, T, TGetCost, TCost>(
-    graph: &Graph<N, E>,
+pub fn find_path<G, T, TGetCost, TCost>(
+    graph: &G,
     start: NodeIndex,
     goal: NodeIndex,
     get_cost: TGetCost,
     traversable: T,
-) -> HashMap<NodeIndex, (TCost, NodeIndex)>
+) -> ShortestPathTree<TCost>
 where
-    TGetCost: Fn(&N, &N) -> TCost,
-    T: Fn(&E) -> bool,
+    G: Neighbours + NodeWeights,
+    TGetCost: Fn(&G::NodeWeight, &G::NodeWeight) -> TCost,
+    T: Fn(&G::EdgeWeight) -> bool,
     TCost: Default + PartialOrd + Default + Clone + Copy,
 {
     let mut unvisited_queue = BinaryHeap::new();
@@ -41,10 +42,10 @@ where
             let source_id = edge_ref.source();
             let dest_id = edge_ref.destination();
 
-            let source_node = graph.get_node(source_id).unwrap();
-            let dest_node = graph.get_node(dest_id).unwrap();
+            let source_node = graph.node_weight(source_id).unwrap();
+            let dest_node = graph.node_weight(dest_id).unwrap();
 
-            let cost = get_cost(&source_node.data, &dest_node.data);
+            let cost = get_cost(source_node, dest_node);
 
             scores.entry(dest_id).or_insert({
                 unvisited_queue.push(MinScored(cost, dest_id));
@@ -53,11 +54,13 @@ where
         }
     }
 
-    scores
+    ShortestPathTree::new(start, scores)
 }
 use std::cmp::Ordering;
 
-use super::graph::{EdgeRef, Graph, NodeIndex};
+use super::graph::{EdgeRef, NodeIndex};
+use super::shortest_path_tree::ShortestPathTree;
+use super::visit::{Neighbours, NodeWeights};
 
 /// `MinScored<K, T>` holds a score `K` and a scored object `T` in
 /// a pair for use with a `BinaryHeap`.
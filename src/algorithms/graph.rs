@@ -14,6 +14,8 @@ pub struct Edge<E> {
 
     pub source: NodeIndex,
     pub destination: NodeIndex,
+    /// Directed edges can only be traversed from `source` to `destination`.
+    pub directed: bool,
 
     outgoing: EdgeIndex,
     incoming: EdgeIndex,
@@ -48,11 +50,32 @@ impl<N, E> Graph<N, E> {
         index
     }
 
+    /// Adds an edge that can be traversed in both directions.
     pub fn add_edge(
         &mut self,
         source_index: NodeIndex,
         dest_index: NodeIndex,
         data: E,
+    ) -> EdgeIndex {
+        self.push_edge(source_index, dest_index, data, false)
+    }
+
+    /// Adds an edge that can only be traversed from `source_index` to `dest_index`.
+    pub fn add_directed_edge(
+        &mut self,
+        source_index: NodeIndex,
+        dest_index: NodeIndex,
+        data: E,
+    ) -> EdgeIndex {
+        self.push_edge(source_index, dest_index, data, true)
+    }
+
+    fn push_edge(
+        &mut self,
+        source_index: NodeIndex,
+        dest_index: NodeIndex,
+        data: E,
+        directed: bool,
     ) -> EdgeIndex {
         let (source_node, destination_node) =
             Graph::<N, E>::get_nodes(&mut self.nodes, source_index, dest_index);
@@ -61,6 +84,7 @@ impl<N, E> Graph<N, E> {
             data,
             source: source_index,
             destination: dest_index,
+            directed,
             outgoing: source_node.outgoing,
             incoming: destination_node.incoming,
         };
@@ -74,10 +98,129 @@ impl<N, E> Graph<N, E> {
         index
     }
 
-    pub fn edges(&self, node_index: NodeIndex) -> Edges<E> {
+    /// Removes an edge, returning its data, or `None` if `edge_index` is
+    /// `EdgeIndex::None` or out of range.
+    ///
+    /// The edge is removed with `Vec::swap_remove`, which moves the last edge into the
+    /// removed edge's slot. The `EdgeIndex` of the last edge therefore changes to
+    /// `edge_index`, and any copy of its old index is no longer valid. All other edge
+    /// and node indices stay valid.
+    pub fn remove_edge(&mut self, edge_index: EdgeIndex) -> Option<E> {
+        let edge_index = match edge_index {
+            EdgeIndex::Index(index) => index,
+            EdgeIndex::None => return None,
+        };
+        let edge = self.edges.get(edge_index)?;
+        let (source, destination) = (edge.source, edge.destination);
+        let (next_outgoing, next_incoming) = (edge.outgoing, edge.incoming);
+
+        self.relink(EdgeType::Outgoing, source, edge_index, next_outgoing);
+        self.relink(EdgeType::Incoming, destination, edge_index, next_incoming);
+
+        let last_index = self.edges.len() - 1;
+        let removed = self.edges.swap_remove(edge_index);
+
+        if edge_index != last_index {
+            let moved = &self.edges[edge_index];
+            let (source, destination) = (moved.source, moved.destination);
+            let index = EdgeIndex::Index(edge_index);
+
+            self.relink(EdgeType::Outgoing, source, last_index, index);
+            self.relink(EdgeType::Incoming, destination, last_index, index);
+        }
+
+        Some(removed.data)
+    }
+
+    /// Removes a node and every edge connected to it, returning the node's data.
+    ///
+    /// The last node is moved into the removed node's slot, so the index of the last
+    /// node changes to `node_index`. Removing the connected edges moves edges as
+    /// described in `remove_edge`.
+    pub fn remove_node(&mut self, node_index: NodeIndex) -> Option<N> {
+        self.nodes.get(node_index)?;
+
+        while let edge @ EdgeIndex::Index(_) = self.nodes[node_index].outgoing {
+            self.remove_edge(edge);
+        }
+        while let edge @ EdgeIndex::Index(_) = self.nodes[node_index].incoming {
+            self.remove_edge(edge);
+        }
+
+        let last_index = self.nodes.len() - 1;
+        let removed = self.nodes.swap_remove(node_index);
+
+        if node_index != last_index {
+            let moved = &self.nodes[node_index];
+            let (mut outgoing, mut incoming) = (moved.outgoing, moved.incoming);
+
+            while let EdgeIndex::Index(edge) = outgoing {
+                self.edges[edge].source = node_index;
+                outgoing = self.edges[edge].outgoing;
+            }
+            while let EdgeIndex::Index(edge) = incoming {
+                self.edges[edge].destination = node_index;
+                incoming = self.edges[edge].incoming;
+            }
+        }
+
+        Some(removed.data)
+    }
+
+    /// Replaces the link to edge `old` in a node's outgoing or incoming list with `new`.
+    fn relink(&mut self, list: EdgeType, node_index: NodeIndex, old: usize, new: EdgeIndex) {
+        let node = &mut self.nodes[node_index];
+        let head = match list {
+            EdgeType::Outgoing => &mut node.outgoing,
+            EdgeType::Incoming => &mut node.incoming,
+        };
+
+        if let EdgeIndex::Index(index) = *head {
+            if index == old {
+                *head = new;
+                return;
+            }
+        }
+
+        let mut current = *head;
+        while let EdgeIndex::Index(index) = current {
+            let edge = &mut self.edges[index];
+            let next = match list {
+                EdgeType::Outgoing => &mut edge.outgoing,
+                EdgeType::Incoming => &mut edge.incoming,
+            };
+
+            if let EdgeIndex::Index(next_index) = *next {
+                if next_index == old {
+                    *next = new;
+                    return;
+                }
+            }
+            current = *next;
+        }
+    }
+
+    /// Edges that can be traversed leaving `node_index`, with `node_index` as their source.
+    pub fn edges(&self, node_index: NodeIndex) -> Edges<'_, E> {
+        self.edges_directed(node_index, EdgeType::Outgoing)
+    }
+
+    /// Same as `edges`.
+    pub fn outgoing_edges(&self, node_index: NodeIndex) -> Edges<'_, E> {
+        self.edges_directed(node_index, EdgeType::Outgoing)
+    }
+
+    /// Edges that can be traversed arriving at `node_index`, with `node_index` as their
+    /// destination.
+    pub fn incoming_edges(&self, node_index: NodeIndex) -> Edges<'_, E> {
+        self.edges_directed(node_index, EdgeType::Incoming)
+    }
+
+    pub fn edges_directed(&self, node_index: NodeIndex, direction: EdgeType) -> Edges<'_, E> {
         let node = self.nodes.get(node_index);
         Edges {
             edges: &self.edges,
+            direction,
             outgoing: match node {
                 Some(node) => node.outgoing,
                 None => EdgeIndex::None,
@@ -148,12 +291,13 @@ impl EdgeIndex {
     }
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum EdgeType {
     Outgoing,
     Incoming,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy)]
 pub struct EdgeReference<'a, E> {
     pub index: EdgeIndex,
     pub nodes: [NodeIndex; 2],
@@ -161,65 +305,83 @@ pub struct EdgeReference<'a, E> {
 }
 
 pub trait EdgeRef {
+    type Weight;
+
     fn source(&self) -> NodeIndex;
     fn destination(&self) -> NodeIndex;
+    fn index(&self) -> EdgeIndex;
+    fn weight(&self) -> &Self::Weight;
 }
-//     fn index(&self) -> EdgeIndex;
-//     fn data(&self) -> &'a E;
-// }
 
 impl<'a, E> EdgeRef for EdgeReference<'a, E> {
+    type Weight = E;
+
     fn source(&self) -> NodeIndex {
         self.nodes[0]
     }
     fn destination(&self) -> NodeIndex {
         self.nodes[1]
     }
+    fn index(&self) -> EdgeIndex {
+        self.index
+    }
+    fn weight(&self) -> &E {
+        self.data
+    }
 }
 
 pub struct Edges<'a, E> {
     edges: &'a [Edge<E>],
+    direction: EdgeType,
     outgoing: EdgeIndex,
     incoming: EdgeIndex,
 }
 
-// Iterator for edges. Should take the next edgeindex.
-// Should check if none -> return none
-// Else try and get value from edges
+// Iterator for edges. Walks the node's outgoing list and then its incoming list,
+// skipping directed edges that point the wrong way for `direction`.
 
 impl<'a, E> Iterator for Edges<'a, E> {
     type Item = EdgeReference<'a, E>;
 
     fn next(&mut self) -> Option<Self::Item> {
-        if let EdgeIndex::Index(index) = self.outgoing {
-            let val = self.edges.get(index);
+        while let EdgeIndex::Index(index) = self.outgoing {
+            let edge = self.edges.get(index)?;
+            self.outgoing = edge.outgoing;
+
+            match self.direction {
+                EdgeType::Outgoing => return Some(edge.reference(index, false)),
+                EdgeType::Incoming if !edge.directed => return Some(edge.reference(index, true)),
+                EdgeType::Incoming => {}
+            }
+        }
 
-            if let Some(edge) = val {
-                self.outgoing = edge.outgoing;
+        while let EdgeIndex::Index(index) = self.incoming {
+            let edge = self.edges.get(index)?;
+            self.incoming = edge.incoming;
 
-                return Some(EdgeReference {
-                    index: EdgeIndex::Index(index),
-                    nodes: [edge.source, edge.destination],
-                    data: &edge.data,
-                });
+            match self.direction {
+                EdgeType::Incoming => return Some(edge.reference(index, false)),
+                EdgeType::Outgoing if !edge.directed => return Some(edge.reference(index, true)),
+                EdgeType::Outgoing => {}
             }
-        };
-
-        if let EdgeIndex::Index(index) = self.incoming {
-            let val = self.edges.get(index);
+        }
 
-            if let Some(edge) = val {
-                self.incoming = edge.incoming;
+        None
+    }
+}
 
-                return Some(EdgeReference {
-                    index: EdgeIndex::Index(index),
-                    // Swap nodes
-                    nodes: [edge.destination, edge.source],
-                    data: &edge.data,
-                });
-            }
+impl<E> Edge<E> {
+    fn reference(&self, index: usize, swap: bool) -> EdgeReference<'_, E> {
+        let nodes = if swap {
+            [self.destination, self.source]
+        } else {
+            [self.source, self.destination]
         };
 
-        None
+        EdgeReference {
+            index: EdgeIndex::Index(index),
+            nodes,
+            data: &self.data,
+        }
     }
 }
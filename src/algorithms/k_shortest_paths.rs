@@ -0,0 +1,134 @@
+use std::collections::HashSet;
+use std::ops::Add;
+
+use super::dijkstra;
+use super::graph::{EdgeRef, EdgeReference, NodeIndex};
+use super::views::EdgeFiltered;
+use super::visit::Neighbours;
+
+/// Up to `k` of the cheapest loopless routes from `start` to `goal`, cheapest first,
+/// using Yen's algorithm.
+///
+/// Each route after the first leaves an earlier route at some node, its spur, and
+/// takes the cheapest way to `goal` that avoids the earlier route's nodes before the
+/// spur and the edges the routes found so far take from it. `get_cost` and
+/// `traversable` are used as in `dijkstra`.
+///
+/// Returns the cost and nodes of every route found, fewer than `k` when there are
+/// no more routes to `goal`.
+pub fn k_shortest_paths<G, T, S, C>(
+    graph: &G,
+    start: NodeIndex,
+    goal: NodeIndex,
+    k: usize,
+    get_cost: S,
+    traversable: T,
+) -> Vec<(C, Vec<NodeIndex>)>
+where
+    G: Neighbours,
+    S: Fn(&G::EdgeWeight) -> C,
+    T: Fn(&G::EdgeWeight) -> bool,
+    C: Default + Ord + Add<C, Output = C> + Copy,
+{
+    if k == 0 {
+        return vec![];
+    }
+
+    let mut found: Vec<Route<C>> = vec![];
+
+    match Route::shortest(graph, start, goal, &get_cost, &traversable) {
+        Some(route) => found.push(route),
+        None => return vec![],
+    }
+
+    let mut candidates: Vec<Route<C>> = vec![];
+    while found.len() < k {
+        let previous = found.last().unwrap();
+
+        for spur_index in 0..previous.nodes.len() - 1 {
+            let spur = previous.nodes[spur_index];
+            let root = &previous.nodes[..=spur_index];
+
+            let removed_edges: HashSet<(NodeIndex, NodeIndex)> = found
+                .iter()
+                .filter(|route| route.nodes.len() > spur_index + 1)
+                .filter(|route| route.nodes[..=spur_index] == *root)
+                .map(|route| (spur, route.nodes[spur_index + 1]))
+                .collect();
+            let removed_nodes: HashSet<NodeIndex> = root[..spur_index].iter().copied().collect();
+
+            let view = EdgeFiltered::new(graph, |edge: &EdgeReference<'_, G::EdgeWeight>| {
+                !removed_edges.contains(&(edge.source(), edge.destination()))
+                    && !removed_nodes.contains(&edge.destination())
+            });
+
+            let spur_route = match Route::shortest(&view, spur, goal, &get_cost, &traversable) {
+                Some(route) => route,
+                None => continue,
+            };
+
+            let root_cost = previous.costs[spur_index];
+            let mut nodes = root.to_vec();
+            nodes.extend(&spur_route.nodes[1..]);
+            let mut costs = previous.costs[..=spur_index].to_vec();
+            costs.extend(spur_route.costs[1..].iter().map(|cost| root_cost + *cost));
+
+            let known = |route: &Route<C>| route.nodes == nodes;
+            if !candidates.iter().any(known) && !found.iter().any(known) {
+                candidates.push(Route { nodes, costs });
+            }
+        }
+
+        // The cheapest candidate, preferring fewer nodes between equal costs.
+        let next = candidates
+            .iter()
+            .enumerate()
+            .min_by_key(|(_, route)| (route.cost(), route.nodes.len()))
+            .map(|(index, _)| index);
+
+        match next {
+            Some(index) => found.push(candidates.swap_remove(index)),
+            None => break,
+        }
+    }
+
+    found
+        .into_iter()
+        .map(|route| (route.cost(), route.nodes))
+        .collect()
+}
+
+/// A route and the cost of reaching each of its nodes.
+struct Route<C> {
+    nodes: Vec<NodeIndex>,
+    costs: Vec<C>,
+}
+
+impl<C> Route<C>
+where
+    C: Default + Ord + Add<C, Output = C> + Copy,
+{
+    fn shortest<G, S, T>(
+        graph: &G,
+        start: NodeIndex,
+        goal: NodeIndex,
+        get_cost: &S,
+        traversable: &T,
+    ) -> Option<Self>
+    where
+        G: Neighbours,
+        S: Fn(&G::EdgeWeight) -> C,
+        T: Fn(&G::EdgeWeight) -> bool,
+    {
+        let tree = dijkstra(graph, start, Some(goal), get_cost, traversable);
+        let nodes = tree.path_to(goal)?;
+        // Every node before the goal was settled, so these costs are final.
+        let costs = nodes.iter().map(|x| tree.cost_to(*x).unwrap()).collect();
+
+        Some(Self { nodes, costs })
+    }
+
+    fn cost(&self) -> C {
+        *self.costs.last().unwrap()
+    }
+}
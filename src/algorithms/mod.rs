@@ -1,8 +1,21 @@
+pub mod astar;
+pub mod bidirectional;
 pub mod convex_hull;
+pub mod csr;
 pub mod dijkstra;
+pub mod distinct_paths;
 pub mod find_path;
 pub mod graph;
+pub mod k_shortest_paths;
+pub mod shortest_path_tree;
+pub mod views;
+pub mod visit;
 
+pub use astar::astar;
+pub use bidirectional::bidirectional_dijkstra;
 pub use convex_hull::convex_hull;
 pub use dijkstra::dijkstra;
+pub use distinct_paths::{distinct_paths, DistinctPath, DistinctPathLimits};
 pub use find_path::find_path;
+pub use k_shortest_paths::k_shortest_paths;
+pub use shortest_path_tree::ShortestPathTree;
@@ -0,0 +1,106 @@
+use std::collections::{hash_map, HashMap};
+
+use super::graph::NodeIndex;
+
+/// The result of a search from `root`: the best known cost of every node reached
+/// and the node it was reached from.
+///
+/// A search that stops at a goal also holds the nodes on its frontier, whose costs
+/// may not be the cheapest.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ShortestPathTree<C> {
+    root: NodeIndex,
+    scores: HashMap<NodeIndex, (C, NodeIndex)>,
+}
+
+impl<C: Copy> ShortestPathTree<C> {
+    /// Wraps the `(cost, previous node)` of every node reached from `root`, where
+    /// `root` is its own previous node.
+    pub(crate) fn new(root: NodeIndex, scores: HashMap<NodeIndex, (C, NodeIndex)>) -> Self {
+        Self { root, scores }
+    }
+
+    /// The node the search started from.
+    pub fn root(&self) -> NodeIndex {
+        self.root
+    }
+
+    /// The number of nodes reached, including the root.
+    pub fn len(&self) -> usize {
+        self.scores.len()
+    }
+
+    /// Whether no node was reached, which only happens when the root is not in the graph.
+    pub fn is_empty(&self) -> bool {
+        self.scores.is_empty()
+    }
+
+    pub fn contains(&self, node: NodeIndex) -> bool {
+        self.scores.contains_key(&node)
+    }
+
+    /// The nodes reached, in no particular order.
+    pub fn reached(&self) -> impl Iterator<Item = NodeIndex> + '_ {
+        self.scores.keys().copied()
+    }
+
+    /// The cost of the route from the root to `node`, `None` when it was not reached.
+    pub fn cost_to(&self, node: NodeIndex) -> Option<C> {
+        self.scores.get(&node).map(|x| x.0)
+    }
+
+    /// The node before `node` on its route, `None` for the root and unreached nodes.
+    pub fn predecessor(&self, node: NodeIndex) -> Option<NodeIndex> {
+        match self.scores.get(&node) {
+            Some(_) if node == self.root => None,
+            Some((_, previous)) => Some(*previous),
+            None => None,
+        }
+    }
+
+    /// The nodes from the root to `node`, `None` when it was not reached.
+    pub fn path_to(&self, node: NodeIndex) -> Option<Vec<NodeIndex>> {
+        if !self.contains(node) {
+            return None;
+        }
+
+        let mut path = vec![node];
+        let mut curr = node;
+        while let Some(previous) = self.predecessor(curr) {
+            path.push(previous);
+            curr = previous;
+        }
+        path.reverse();
+
+        Some(path)
+    }
+
+    /// The `(node, cost)` of every node reached, in no particular order.
+    pub fn iter(&self) -> Iter<'_, C> {
+        Iter(self.scores.iter())
+    }
+}
+
+impl<'a, C: Copy> IntoIterator for &'a ShortestPathTree<C> {
+    type Item = (NodeIndex, C);
+    type IntoIter = Iter<'a, C>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// Iterator over the `(node, cost)` of every node in a `ShortestPathTree`.
+pub struct Iter<'a, C>(hash_map::Iter<'a, NodeIndex, (C, NodeIndex)>);
+
+impl<'a, C: Copy> Iterator for Iter<'a, C> {
+    type Item = (NodeIndex, C);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next().map(|(node, (cost, _))| (*node, *cost))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+}
@@ -0,0 +1,134 @@
+//! Views that change how a graph is traversed without copying it.
+
+use std::iter::Filter;
+
+use super::graph::{EdgeReference, NodeIndex};
+use super::visit::{EdgeWeights, GraphBase, IncomingNeighbours, Neighbours, NodeWeights};
+
+/// A graph with only the edges accepted by `filter`.
+pub struct EdgeFiltered<'g, G, F> {
+    graph: &'g G,
+    filter: F,
+}
+
+impl<'g, G, F> EdgeFiltered<'g, G, F>
+where
+    G: GraphBase,
+    F: Fn(&EdgeReference<'_, G::EdgeWeight>) -> bool,
+{
+    pub fn new(graph: &'g G, filter: F) -> Self {
+        Self { graph, filter }
+    }
+}
+
+impl<'g, G: GraphBase, F> GraphBase for EdgeFiltered<'g, G, F> {
+    type NodeWeight = G::NodeWeight;
+    type EdgeWeight = G::EdgeWeight;
+
+    fn node_count(&self) -> usize {
+        self.graph.node_count()
+    }
+}
+
+impl<'g, G: NodeWeights, F> NodeWeights for EdgeFiltered<'g, G, F> {
+    fn node_weight(&self, node: NodeIndex) -> Option<&G::NodeWeight> {
+        self.graph.node_weight(node)
+    }
+}
+
+impl<'g, G: EdgeWeights, F> EdgeWeights for EdgeFiltered<'g, G, F> {
+    fn edge_weight(&self, edge: usize) -> Option<&G::EdgeWeight> {
+        self.graph.edge_weight(edge)
+    }
+}
+
+impl<'g, G, F> Neighbours for EdgeFiltered<'g, G, F>
+where
+    G: Neighbours,
+    F: Fn(&EdgeReference<'_, G::EdgeWeight>) -> bool,
+{
+    type Edges<'a>
+        = Filter<G::Edges<'a>, &'a F>
+    where
+        Self: 'a;
+
+    fn edges(&self, node: NodeIndex) -> Self::Edges<'_> {
+        self.graph.edges(node).filter(&self.filter)
+    }
+}
+
+impl<'g, G, F> IncomingNeighbours for EdgeFiltered<'g, G, F>
+where
+    G: IncomingNeighbours,
+    F: Fn(&EdgeReference<'_, G::EdgeWeight>) -> bool,
+{
+    fn incoming_edges(&self, node: NodeIndex) -> Self::Edges<'_> {
+        self.graph.incoming_edges(node).filter(&self.filter)
+    }
+}
+
+/// A graph with every edge traversed in the opposite direction.
+///
+/// Searching a reversed graph from a node finds the routes that end at it.
+pub struct Reversed<'g, G>(pub &'g G);
+
+impl<'g, G: GraphBase> GraphBase for Reversed<'g, G> {
+    type NodeWeight = G::NodeWeight;
+    type EdgeWeight = G::EdgeWeight;
+
+    fn node_count(&self) -> usize {
+        self.0.node_count()
+    }
+}
+
+impl<'g, G: NodeWeights> NodeWeights for Reversed<'g, G> {
+    fn node_weight(&self, node: NodeIndex) -> Option<&G::NodeWeight> {
+        self.0.node_weight(node)
+    }
+}
+
+impl<'g, G: EdgeWeights> EdgeWeights for Reversed<'g, G> {
+    fn edge_weight(&self, edge: usize) -> Option<&G::EdgeWeight> {
+        self.0.edge_weight(edge)
+    }
+}
+
+impl<'g, G: IncomingNeighbours> Neighbours for Reversed<'g, G> {
+    type Edges<'a>
+        = ReversedEdges<G::Edges<'a>>
+    where
+        Self: 'a;
+
+    fn edges(&self, node: NodeIndex) -> Self::Edges<'_> {
+        ReversedEdges(self.0.incoming_edges(node))
+    }
+}
+
+impl<'g, G: IncomingNeighbours> IncomingNeighbours for Reversed<'g, G> {
+    fn incoming_edges(&self, node: NodeIndex) -> Self::Edges<'_> {
+        ReversedEdges(self.0.edges(node))
+    }
+}
+
+/// Swaps the source and destination of each edge from the wrapped iterator.
+pub struct ReversedEdges<I>(I);
+
+impl<'a, E: 'a, I> Iterator for ReversedEdges<I>
+where
+    I: Iterator<Item = EdgeReference<'a, E>>,
+{
+    type Item = EdgeReference<'a, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let edge = self.0.next()?;
+
+        Some(EdgeReference {
+            nodes: [edge.nodes[1], edge.nodes[0]],
+            ..edge
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+}
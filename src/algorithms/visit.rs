@@ -0,0 +1,117 @@
+//! Traits the algorithms use to read a graph, so they run unchanged on `Graph`,
+//! `FrozenGraph` and the views in `views`.
+
+use super::csr::{FrozenEdges, FrozenGraph};
+use super::graph::{EdgeReference, Edges, Graph, NodeIndex};
+
+/// The weight types of a graph and the number of nodes in it.
+///
+/// Nodes are indexed `0..node_count()`.
+pub trait GraphBase {
+    type NodeWeight;
+    type EdgeWeight;
+
+    fn node_count(&self) -> usize;
+}
+
+/// Access to node weights by index.
+pub trait NodeWeights: GraphBase {
+    fn node_weight(&self, node: NodeIndex) -> Option<&Self::NodeWeight>;
+}
+
+/// Access to edge weights by index.
+pub trait EdgeWeights: GraphBase {
+    fn edge_weight(&self, edge: usize) -> Option<&Self::EdgeWeight>;
+}
+
+/// Iteration over the edges that can be traversed leaving a node.
+pub trait Neighbours: GraphBase {
+    type Edges<'a>: Iterator<Item = EdgeReference<'a, Self::EdgeWeight>>
+    where
+        Self: 'a;
+
+    /// Edges that can be traversed leaving `node`, with `node` as their source.
+    fn edges(&self, node: NodeIndex) -> Self::Edges<'_>;
+}
+
+/// Iteration over the edges that can be traversed arriving at a node.
+pub trait IncomingNeighbours: Neighbours {
+    /// Edges that can be traversed arriving at `node`, with `node` as their destination.
+    fn incoming_edges(&self, node: NodeIndex) -> Self::Edges<'_>;
+}
+
+impl<N, E> GraphBase for Graph<N, E> {
+    type NodeWeight = N;
+    type EdgeWeight = E;
+
+    fn node_count(&self) -> usize {
+        self.raw_nodes().len()
+    }
+}
+
+impl<N, E> NodeWeights for Graph<N, E> {
+    fn node_weight(&self, node: NodeIndex) -> Option<&N> {
+        self.get_node(node).map(|x| &x.data)
+    }
+}
+
+impl<N, E> EdgeWeights for Graph<N, E> {
+    fn edge_weight(&self, edge: usize) -> Option<&E> {
+        self.raw_edges().get(edge).map(|x| &x.data)
+    }
+}
+
+impl<N, E> Neighbours for Graph<N, E> {
+    type Edges<'a>
+        = Edges<'a, E>
+    where
+        Self: 'a;
+
+    fn edges(&self, node: NodeIndex) -> Edges<'_, E> {
+        Graph::edges(self, node)
+    }
+}
+
+impl<N, E> IncomingNeighbours for Graph<N, E> {
+    fn incoming_edges(&self, node: NodeIndex) -> Edges<'_, E> {
+        Graph::incoming_edges(self, node)
+    }
+}
+
+impl<N, E> GraphBase for FrozenGraph<N, E> {
+    type NodeWeight = N;
+    type EdgeWeight = E;
+
+    fn node_count(&self) -> usize {
+        self.raw_nodes().len()
+    }
+}
+
+impl<N, E> NodeWeights for FrozenGraph<N, E> {
+    fn node_weight(&self, node: NodeIndex) -> Option<&N> {
+        self.get_node(node)
+    }
+}
+
+impl<N, E> EdgeWeights for FrozenGraph<N, E> {
+    fn edge_weight(&self, edge: usize) -> Option<&E> {
+        self.raw_edges().get(edge).map(|x| &x.data)
+    }
+}
+
+impl<N, E> Neighbours for FrozenGraph<N, E> {
+    type Edges<'a>
+        = FrozenEdges<'a, E>
+    where
+        Self: 'a;
+
+    fn edges(&self, node: NodeIndex) -> FrozenEdges<'_, E> {
+        FrozenGraph::edges(self, node)
+    }
+}
+
+impl<N, E> IncomingNeighbours for FrozenGraph<N, E> {
+    fn incoming_edges(&self, node: NodeIndex) -> FrozenEdges<'_, E> {
+        FrozenGraph::incoming_edges(self, node)
+    }
+}
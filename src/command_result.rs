@@ -0,0 +1,369 @@
+use std::fmt::{self, Display};
+
+use serde::Serialize;
+
+use crate::models::{Place, TravelMode};
+
+/// Output of a single processed `Command`.
+///
+/// The `Display` impl writes the same text format the command file expects, while
+/// `Serialize` gives an object tagged with the command name for JSON output.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(tag = "command")]
+pub enum CommandResult {
+    MaxDist {
+        a: Place,
+        b: Place,
+        distance: f64,
+    },
+    MaxLink {
+        a: i32,
+        b: i32,
+        distance: f64,
+    },
+    FindDist {
+        a: Place,
+        b: Place,
+        distance: f64,
+    },
+    FindNeighbour {
+        place: i32,
+        neighbours: Vec<i32>,
+    },
+    Check {
+        mode: TravelMode,
+        places: Vec<i32>,
+        legs: Vec<CheckLeg>,
+    },
+    /// A route given as its sequence of places.
+    #[serde(rename = "FindRoute")]
+    Route {
+        mode: TravelMode,
+        start: i32,
+        goal: i32,
+        route: Vec<Place>,
+    },
+    /// The route with the fewest links as its sequence of places.
+    #[serde(rename = "FindShortestRoute")]
+    ShortestRoute {
+        mode: TravelMode,
+        start: i32,
+        goal: i32,
+        route: Vec<Place>,
+    },
+    /// A route given leg by leg with distances.
+    #[serde(rename = "FindShortestDistanceRoute")]
+    DistanceRoute {
+        mode: TravelMode,
+        start: i32,
+        goal: i32,
+        legs: Vec<RouteLeg>,
+    },
+    /// A route given leg by leg with estimated travel times.
+    #[serde(rename = "FindFastestRoute")]
+    FastestRoute {
+        mode: TravelMode,
+        start: i32,
+        goal: i32,
+        legs: Vec<TimedLeg>,
+    },
+    /// The shortest route by distance through `places` in order, one segment between
+    /// each consecutive pair. `route` joins the segments, `None` when one is unreachable.
+    #[serde(rename = "FindRouteVia")]
+    RouteVia {
+        mode: TravelMode,
+        places: Vec<i32>,
+        segments: Vec<ViaSegment>,
+        route: Option<Vec<Place>>,
+    },
+    /// Up to `count` routes, shortest first.
+    #[serde(rename = "FindAlternativeRoutes")]
+    AlternativeRoutes {
+        mode: TravelMode,
+        start: i32,
+        goal: i32,
+        count: usize,
+        routes: Vec<AlternativeRoute>,
+    },
+    /// Up to `count` routes, shortest first, that share at most `max_overlap` of their
+    /// distance with the shortest and are at most `max_stretch` times its distance.
+    #[serde(rename = "FindDistinctRoutes")]
+    DistinctRoutes {
+        mode: TravelMode,
+        start: i32,
+        goal: i32,
+        count: usize,
+        max_overlap: f64,
+        max_stretch: f64,
+        routes: Vec<DistinctRoute>,
+    },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct CheckLeg {
+    pub from: i32,
+    pub to: i32,
+    pub connects: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct RouteLeg {
+    pub from: Place,
+    pub to: Place,
+    pub distance: f64,
+}
+
+/// A leg of a `FastestRoute`, travelled on a link of `mode` at that mode's speed.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct TimedLeg {
+    pub from: Place,
+    pub to: Place,
+    pub mode: TravelMode,
+    pub distance: f64,
+    pub minutes: f64,
+}
+
+/// A segment of a `RouteVia`, given leg by leg, `None` when `to` cannot be reached
+/// from `from`.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ViaSegment {
+    pub from: i32,
+    pub to: i32,
+    pub legs: Option<Vec<RouteLeg>>,
+}
+
+impl ViaSegment {
+    /// Sum of the leg distances, `None` when the segment is unreachable.
+    pub fn distance(&self) -> Option<f64> {
+        self.legs
+            .as_ref()
+            // Folded from 0.0 as an empty `sum` of floats is -0.0.
+            .map(|legs| legs.iter().fold(0.0, |total, leg| total + leg.distance))
+    }
+}
+
+/// One of the routes of an `AlternativeRoutes` result.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct AlternativeRoute {
+    pub places: Vec<Place>,
+    pub distance: f64,
+}
+
+impl AlternativeRoute {
+    /// The number of links travelled.
+    pub fn hops(&self) -> usize {
+        self.places.len().saturating_sub(1)
+    }
+}
+
+/// One of the routes of a `DistinctRoutes` result.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct DistinctRoute {
+    pub places: Vec<Place>,
+    pub distance: f64,
+    /// The fraction of the distance shared with the shortest route.
+    pub overlap: f64,
+}
+
+impl DistinctRoute {
+    /// The number of links travelled.
+    pub fn hops(&self) -> usize {
+        self.places.len().saturating_sub(1)
+    }
+}
+
+impl CommandResult {
+    /// Sum of the leg distances of a `DistanceRoute`.
+    pub fn total_distance(&self) -> Option<f64> {
+        match self {
+            CommandResult::DistanceRoute { legs, .. } => {
+                Some(legs.iter().map(|leg| leg.distance).sum())
+            }
+            _ => None,
+        }
+    }
+
+    /// Sum of the leg times of a `FastestRoute` in minutes.
+    pub fn total_minutes(&self) -> Option<f64> {
+        match self {
+            CommandResult::FastestRoute { legs, .. } => {
+                Some(legs.iter().map(|leg| leg.minutes).sum())
+            }
+            _ => None,
+        }
+    }
+}
+
+impl Display for CommandResult {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CommandResult::MaxDist { a, b, distance } => {
+                write!(f, "MaxDist\n{}, {}, {}", a, b, distance)
+            }
+            CommandResult::MaxLink { a, b, distance } => {
+                write!(f, "MaxLink\n{},{},{:.1}", a, b, distance)
+            }
+            CommandResult::FindDist { a, b, distance } => {
+                write!(f, "FindDist\n{},{},{:.3}", a.name, b.name, distance)
+            }
+            CommandResult::FindNeighbour { place, neighbours } => {
+                write!(f, "FindNeighbour {}", place)?;
+                for neighbour in neighbours {
+                    write!(f, "\n{}", neighbour)?;
+                }
+                Ok(())
+            }
+            CommandResult::Check { mode, places, legs } => {
+                let string_ids: Vec<String> = places.iter().map(|x| x.to_string()).collect();
+                write!(f, "Check {} {}", mode, string_ids.join(" "))?;
+
+                for leg in legs {
+                    let outcome = if leg.connects { "PASS" } else { "FAIL" };
+                    write!(f, "\n{},{},{}", leg.from, leg.to, outcome)?;
+                }
+                Ok(())
+            }
+            CommandResult::Route {
+                mode,
+                start,
+                goal,
+                route,
+            } => {
+                write!(f, "FindRoute {} {} {}", mode, start, goal)?;
+                write_places(f, route)
+            }
+            CommandResult::ShortestRoute {
+                mode,
+                start,
+                goal,
+                route,
+            } => {
+                write!(f, "FindShortestRoute {} {} {}", mode, start, goal)?;
+                write_places(f, route)
+            }
+            CommandResult::DistanceRoute {
+                mode,
+                start,
+                goal,
+                legs,
+            } => {
+                write!(f, "FindShortestDistanceRoute {} {} {}", mode, start, goal)?;
+
+                for leg in legs {
+                    write!(f, "\n{},{},{:.3}", leg.from.id, leg.to.id, leg.distance)?;
+                }
+                write!(f, "\nTotal,{:.3}", self.total_distance().unwrap())
+            }
+            CommandResult::FastestRoute {
+                mode,
+                start,
+                goal,
+                legs,
+            } => {
+                write!(f, "FindFastestRoute {} {} {}", mode, start, goal)?;
+
+                for leg in legs {
+                    write!(
+                        f,
+                        "\n{},{},{},{:.1}",
+                        leg.from.id, leg.to.id, leg.mode, leg.minutes
+                    )?;
+                }
+                write!(f, "\nTotal,{:.1}", self.total_minutes().unwrap())
+            }
+            CommandResult::RouteVia {
+                mode,
+                places,
+                segments,
+                route,
+            } => {
+                let string_ids: Vec<String> = places.iter().map(|x| x.to_string()).collect();
+                write!(f, "FindRouteVia {} {}", mode, string_ids.join(" "))?;
+
+                for segment in segments {
+                    write!(f, "\n{},{},", segment.from, segment.to)?;
+                    match &segment.legs {
+                        Some(legs) => {
+                            let mut ids = vec![segment.from.to_string()];
+                            ids.extend(legs.iter().map(|leg| leg.to.id.to_string()));
+                            write!(f, "{:.3},{}", segment.distance().unwrap(), ids.join(" "))?;
+                        }
+                        None => write!(f, "FAIL")?,
+                    }
+                }
+
+                match route {
+                    Some(route) => {
+                        let distance: f64 = segments.iter().filter_map(|x| x.distance()).sum();
+                        let ids: Vec<String> = route.iter().map(|x| x.id.to_string()).collect();
+                        write!(f, "\nTotal,{:.3},{}", distance, ids.join(" "))
+                    }
+                    None => write!(f, "\nFail"),
+                }
+            }
+            CommandResult::AlternativeRoutes {
+                mode,
+                start,
+                goal,
+                count,
+                routes,
+            } => {
+                write!(
+                    f,
+                    "FindAlternativeRoutes {} {} {} {}",
+                    mode, start, goal, count
+                )?;
+
+                for (rank, route) in routes.iter().enumerate() {
+                    let ids: Vec<String> = route.places.iter().map(|x| x.id.to_string()).collect();
+                    write!(
+                        f,
+                        "\n{},{},{:.3},{}",
+                        rank + 1,
+                        route.hops(),
+                        route.distance,
+                        ids.join(" ")
+                    )?;
+                }
+                Ok(())
+            }
+            CommandResult::DistinctRoutes {
+                mode,
+                start,
+                goal,
+                count,
+                max_overlap,
+                max_stretch,
+                routes,
+            } => {
+                write!(
+                    f,
+                    "FindDistinctRoutes {} {} {} {} {} {}",
+                    mode, start, goal, count, max_overlap, max_stretch
+                )?;
+
+                for (rank, route) in routes.iter().enumerate() {
+                    let ids: Vec<String> = route.places.iter().map(|x| x.id.to_string()).collect();
+                    write!(
+                        f,
+                        "\n{},{},{:.3},{:.3},{}",
+                        rank + 1,
+                        route.hops(),
+                        route.distance,
+                        route.overlap,
+                        ids.join(" ")
+                    )?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+/// Writes the places of a `Route` or `ShortestRoute` one per line.
+fn write_places(f: &mut fmt::Formatter<'_>, route: &[Place]) -> fmt::Result {
+    for place in route {
+        write!(f, "\n{}", place)?;
+    }
+    Ok(())
+}
@@ -0,0 +1,118 @@
+use crate::models::Place;
+
+/// Mean radius of the Earth in kilometres, used by the haversine formula.
+const EARTH_RADIUS_KM: f64 = 6371.0088;
+
+const WGS84_SEMI_MAJOR_AXIS_M: f64 = 6_378_137.0;
+const WGS84_FLATTENING: f64 = 1.0 / 298.257_223_563;
+
+/// How the distance between two places is measured.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DistanceModel {
+    /// Straight line between the projected grid positions of the places.
+    #[default]
+    Projected,
+    /// Great-circle distance on a sphere of the Earth's mean radius.
+    Haversine,
+    /// Geodesic distance on the WGS84 ellipsoid.
+    Vincenty,
+}
+
+impl DistanceModel {
+    /// Distance in kilometres between `a` and `b`.
+    pub fn distance(self, a: &Place, b: &Place) -> f64 {
+        match self {
+            DistanceModel::Projected => a.distance(b),
+            DistanceModel::Haversine => haversine(a.latitude, a.longitude, b.latitude, b.longitude),
+            DistanceModel::Vincenty => vincenty(a.latitude, a.longitude, b.latitude, b.longitude),
+        }
+    }
+}
+
+/// Great-circle distance in kilometres between two points given in degrees.
+pub fn haversine(lat_a: f64, lon_a: f64, lat_b: f64, lon_b: f64) -> f64 {
+    let (phi_a, phi_b) = (lat_a.to_radians(), lat_b.to_radians());
+    let d_phi = phi_b - phi_a;
+    let d_lambda = (lon_b - lon_a).to_radians();
+
+    let h =
+        (d_phi / 2.0).sin().powi(2) + phi_a.cos() * phi_b.cos() * (d_lambda / 2.0).sin().powi(2);
+
+    2.0 * EARTH_RADIUS_KM * h.sqrt().min(1.0).asin()
+}
+
+/// Distance in kilometres between two points given in degrees along the WGS84
+/// ellipsoid, using Vincenty's inverse formula.
+///
+/// The iteration does not converge for nearly antipodal points, where the
+/// haversine distance is returned instead.
+pub fn vincenty(lat_a: f64, lon_a: f64, lat_b: f64, lon_b: f64) -> f64 {
+    const MAX_ITERATIONS: usize = 200;
+    const TOLERANCE: f64 = 1e-12;
+
+    let a = WGS84_SEMI_MAJOR_AXIS_M;
+    let f = WGS84_FLATTENING;
+    let b = (1.0 - f) * a;
+
+    let l = (lon_b - lon_a).to_radians();
+    let u_a = ((1.0 - f) * lat_a.to_radians().tan()).atan();
+    let u_b = ((1.0 - f) * lat_b.to_radians().tan()).atan();
+    let (sin_u_a, cos_u_a) = u_a.sin_cos();
+    let (sin_u_b, cos_u_b) = u_b.sin_cos();
+
+    let mut lambda = l;
+    for _ in 0..MAX_ITERATIONS {
+        let (sin_lambda, cos_lambda) = lambda.sin_cos();
+        let sin_sigma = ((cos_u_b * sin_lambda).powi(2)
+            + (cos_u_a * sin_u_b - sin_u_a * cos_u_b * cos_lambda).powi(2))
+        .sqrt();
+
+        if sin_sigma == 0.0 {
+            // Coincident points.
+            return 0.0;
+        }
+
+        let cos_sigma = sin_u_a * sin_u_b + cos_u_a * cos_u_b * cos_lambda;
+        let sigma = sin_sigma.atan2(cos_sigma);
+        let sin_alpha = cos_u_a * cos_u_b * sin_lambda / sin_sigma;
+        let cos_sq_alpha = 1.0 - sin_alpha * sin_alpha;
+
+        // Both points are on the equator when cos_sq_alpha is zero.
+        let cos_2_sigma_m = if cos_sq_alpha == 0.0 {
+            0.0
+        } else {
+            cos_sigma - 2.0 * sin_u_a * sin_u_b / cos_sq_alpha
+        };
+
+        let c = f / 16.0 * cos_sq_alpha * (4.0 + f * (4.0 - 3.0 * cos_sq_alpha));
+        let previous = lambda;
+        lambda = l
+            + (1.0 - c)
+                * f
+                * sin_alpha
+                * (sigma
+                    + c * sin_sigma
+                        * (cos_2_sigma_m
+                            + c * cos_sigma * (-1.0 + 2.0 * cos_2_sigma_m * cos_2_sigma_m)));
+
+        if (lambda - previous).abs() < TOLERANCE {
+            let u_sq = cos_sq_alpha * (a * a - b * b) / (b * b);
+            let big_a =
+                1.0 + u_sq / 16384.0 * (4096.0 + u_sq * (-768.0 + u_sq * (320.0 - 175.0 * u_sq)));
+            let big_b = u_sq / 1024.0 * (256.0 + u_sq * (-128.0 + u_sq * (74.0 - 47.0 * u_sq)));
+            let delta_sigma = big_b
+                * sin_sigma
+                * (cos_2_sigma_m
+                    + big_b / 4.0
+                        * (cos_sigma * (-1.0 + 2.0 * cos_2_sigma_m * cos_2_sigma_m)
+                            - big_b / 6.0
+                                * cos_2_sigma_m
+                                * (-3.0 + 4.0 * sin_sigma * sin_sigma)
+                                * (-3.0 + 4.0 * cos_2_sigma_m * cos_2_sigma_m)));
+
+            return b * big_a * (sigma - delta_sigma) / 1000.0;
+        }
+    }
+
+    haversine(lat_a, lon_a, lat_b, lon_b)
+}
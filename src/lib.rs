@@ -1,5 +1,12 @@
+pub mod command_result;
+pub mod distance;
+pub mod mode_view;
 pub mod models;
+pub mod modes;
 pub mod process_command;
+pub mod process_error;
+pub mod projection;
+pub mod repl;
 
 pub mod algorithms;
 pub mod startup;
@@ -1,24 +1,158 @@
+use std::fs::File;
+use std::io::{self, BufWriter, Read, Write};
+use std::{env, process};
+
+use serde::Serialize;
+
+use learning_graph::command_result::CommandResult;
+use learning_graph::models::Command;
+use learning_graph::modes::ModeRegistry;
 use learning_graph::process_command::CommandProcessor;
-use learning_graph::startup::deserialization::{read_commands, read_links, read_places};
+use learning_graph::process_error::ProcessError;
+use learning_graph::repl;
+use learning_graph::startup::cli::{Options, OutputFormat, USAGE};
+use learning_graph::startup::deserialization::{
+    parse_commands, read_commands, read_modes, CommandsError, Strictness,
+};
 use learning_graph::startup::graph_builder::{self};
+use learning_graph::startup::load_error::ErrorPolicy;
 
 fn main() {
-    const PLACES_PATH: &str = "Places.csv";
-    const LINKS_PATH: &str = "Links.csv";
-    const COMMAND_PATH: &str = "Commands.txt";
+    let options = match Options::parse(env::args().skip(1)) {
+        Ok(options) => options,
+        Err(error) => {
+            eprintln!("error: {}\n\n{}", error, USAGE);
+            process::exit(2);
+        }
+    };
+
+    if options.help {
+        println!("{}", USAGE);
+        return;
+    }
+
+    let modes = match &options.modes {
+        Some(path) => read_modes(path).unwrap_or_else(|error| {
+            eprintln!("{}", error);
+            process::exit(1);
+        }),
+        None => ModeRegistry::default(),
+    };
+
+    let (graph, map) = match graph_builder::load(
+        &options.places,
+        &options.links,
+        ErrorPolicy::CollectAll,
+        &modes,
+        options.projection,
+    ) {
+        Ok(network) => network,
+        Err(errors) => {
+            for error in errors {
+                eprintln!("{}", error);
+            }
+            process::exit(1);
+        }
+    };
+
+    let processor = CommandProcessor::new(graph, map)
+        .with_modes(modes)
+        .with_distance_model(options.distance);
+
+    if options.repl {
+        let stdin = io::stdin();
+        if let Err(error) = repl::run(&processor, stdin.lock(), io::stdout()) {
+            eprintln!("{}", error);
+            process::exit(1);
+        }
+        return;
+    }
+
+    let strictness = if options.strict {
+        Strictness::Strict
+    } else {
+        Strictness::Lenient
+    };
+
+    let commands = match &options.commands {
+        Some(path) => read_commands(path, strictness, processor.modes()),
+        None => read_stdin().and_then(|contents| {
+            parse_commands(&contents, strictness, processor.modes()).map_err(CommandsError::Parse)
+        }),
+    };
+    let commands = commands.unwrap_or_else(|error| {
+        eprintln!("{}", error);
+        process::exit(1);
+    });
+
+    let mut output: Box<dyn Write> = match &options.output {
+        Some(path) => match File::create(path) {
+            Ok(file) => Box::new(BufWriter::new(file)),
+            Err(error) => {
+                eprintln!("could not create {}: {}", path, error);
+                process::exit(1);
+            }
+        },
+        None => Box::new(io::stdout()),
+    };
 
-    let nodes = read_places(PLACES_PATH);
-    let links = read_links(LINKS_PATH);
+    let results: Vec<(Command, Result<CommandResult, ProcessError>)> = commands
+        .into_iter()
+        .map(|x| (x.clone(), processor.process(x)))
+        .collect();
 
-    let (graph, map) = graph_builder::build(nodes, links);
+    if let Err(error) = write_results(&mut output, options.format, &results) {
+        eprintln!("could not write output: {}", error);
+        process::exit(1);
+    }
+}
 
-    let processor = CommandProcessor::new(graph, map);
+fn read_stdin() -> Result<String, CommandsError> {
+    let mut contents = String::new();
+    io::stdin()
+        .read_to_string(&mut contents)
+        .map_err(|err| CommandsError::Io(format!("could not read commands from stdin: {}", err)))?;
+    Ok(contents)
+}
 
-    let commands = read_commands(COMMAND_PATH).unwrap();
+/// A processed command in JSON output, either its result or the command that failed
+/// and why.
+#[derive(Serialize)]
+#[serde(untagged)]
+enum JsonEntry<'a> {
+    Result(&'a CommandResult),
+    Error { command: String, error: String },
+}
 
-    let results: Vec<String> = commands.into_iter().map(|x| processor.process(x)).collect();
+fn write_results(
+    output: &mut dyn Write,
+    format: OutputFormat,
+    results: &[(Command, Result<CommandResult, ProcessError>)],
+) -> io::Result<()> {
+    match format {
+        OutputFormat::Text => {
+            for (command, result) in results {
+                match result {
+                    Ok(result) => writeln!(output, "{}\n", result)?,
+                    Err(error) => writeln!(output, "{}\n", error.text_output(command))?,
+                }
+            }
+        }
+        OutputFormat::Json => {
+            let entries: Vec<JsonEntry> = results
+                .iter()
+                .map(|(command, result)| match result {
+                    Ok(result) => JsonEntry::Result(result),
+                    Err(error) => JsonEntry::Error {
+                        command: command.to_string(),
+                        error: error.to_string(),
+                    },
+                })
+                .collect();
 
-    for result in results {
-        println!("{}\n", result);
+            serde_json::to_writer_pretty(&mut *output, &entries)?;
+            writeln!(output)?;
+        }
     }
+    output.flush()
 }
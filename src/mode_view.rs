@@ -0,0 +1,120 @@
+use std::collections::BTreeSet;
+
+use crate::{
+    algorithms::{
+        graph::{EdgeReference, NodeIndex},
+        visit::{EdgeWeights, GraphBase, IncomingNeighbours, Neighbours, NodeWeights},
+    },
+    models::{Link, TravelMode},
+    modes::ModeRegistry,
+};
+
+/// A view of a transport network with only the links `mode` can traverse under the
+/// rules in a `ModeRegistry`.
+///
+/// The view borrows the network, so creating one per query costs nothing. It works
+/// over `Graph` and `FrozenGraph` and can be passed to any algorithm in `algorithms`.
+/// A mode missing from the registry cannot traverse any links.
+pub struct ModeView<'g, G> {
+    graph: &'g G,
+    mode: TravelMode,
+    links: Option<&'g BTreeSet<TravelMode>>,
+}
+
+impl<'g, G> ModeView<'g, G>
+where
+    G: GraphBase<EdgeWeight = Link>,
+{
+    pub fn new(graph: &'g G, mode: &TravelMode, modes: &'g ModeRegistry) -> Self {
+        Self {
+            graph,
+            mode: mode.clone(),
+            links: modes.get(mode.name()).map(|definition| &definition.links),
+        }
+    }
+
+    pub fn mode(&self) -> &TravelMode {
+        &self.mode
+    }
+
+    pub fn graph(&self) -> &'g G {
+        self.graph
+    }
+
+    fn can_traverse(&self, link: &Link) -> bool {
+        self.links.is_some_and(|links| links.contains(&link.mode))
+    }
+}
+
+impl<'g, G: GraphBase> GraphBase for ModeView<'g, G> {
+    type NodeWeight = G::NodeWeight;
+    type EdgeWeight = G::EdgeWeight;
+
+    fn node_count(&self) -> usize {
+        self.graph.node_count()
+    }
+}
+
+impl<'g, G: NodeWeights> NodeWeights for ModeView<'g, G> {
+    fn node_weight(&self, node: NodeIndex) -> Option<&G::NodeWeight> {
+        self.graph.node_weight(node)
+    }
+}
+
+impl<'g, G> EdgeWeights for ModeView<'g, G>
+where
+    G: EdgeWeights<EdgeWeight = Link>,
+{
+    /// `None` for links the mode cannot traverse.
+    fn edge_weight(&self, edge: usize) -> Option<&Link> {
+        self.graph
+            .edge_weight(edge)
+            .filter(|link| self.can_traverse(link))
+    }
+}
+
+impl<'g, G> Neighbours for ModeView<'g, G>
+where
+    G: Neighbours<EdgeWeight = Link>,
+{
+    type Edges<'a>
+        = ModeEdges<'a, G::Edges<'a>>
+    where
+        Self: 'a;
+
+    fn edges(&self, node: NodeIndex) -> Self::Edges<'_> {
+        ModeEdges {
+            edges: self.graph.edges(node),
+            links: self.links,
+        }
+    }
+}
+
+impl<'g, G> IncomingNeighbours for ModeView<'g, G>
+where
+    G: IncomingNeighbours<EdgeWeight = Link>,
+{
+    fn incoming_edges(&self, node: NodeIndex) -> Self::Edges<'_> {
+        ModeEdges {
+            edges: self.graph.incoming_edges(node),
+            links: self.links,
+        }
+    }
+}
+
+pub struct ModeEdges<'m, I> {
+    edges: I,
+    links: Option<&'m BTreeSet<TravelMode>>,
+}
+
+impl<'a, 'm, I> Iterator for ModeEdges<'m, I>
+where
+    I: Iterator<Item = EdgeReference<'a, Link>>,
+{
+    type Item = EdgeReference<'a, Link>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let links = self.links?;
+        self.edges.find(|x| links.contains(&x.data.mode))
+    }
+}
@@ -1,28 +1,98 @@
-use coord_transforms::{geo::ll2utm, prelude::Vector2, structs::geo_ellipsoid};
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 use std::fmt::Display;
 
-#[derive(Debug, PartialEq, Deserialize, Clone, Copy, Eq, PartialOrd, Ord, Hash)]
-pub enum TravelMode {
-    Foot,
-    Bike,
-    Car,
-    Bus,
-    Ship,
-    Rail,
+use crate::projection::Projection;
+
+/// The name of a travel mode defined in a `ModeRegistry`, e.g. `Foot` or `Tram`.
+#[derive(Debug, PartialEq, Deserialize, Serialize, Clone, Eq, PartialOrd, Ord, Hash)]
+#[serde(transparent)]
+pub struct TravelMode(String);
+
+impl TravelMode {
+    pub fn new(name: &str) -> Self {
+        Self(name.to_string())
+    }
+
+    pub fn name(&self) -> &str {
+        &self.0
+    }
 }
 
 impl Display for TravelMode {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{:?}", self)
+        write!(f, "{}", self.0)
     }
 }
 
-#[derive(Debug, Deserialize, Clone, Copy)]
+/// A link between two places, read from a row of the links file.
+///
+/// Only `start`, `end` and `mode` are required. The remaining columns are optional
+/// and may be left empty or omitted from the end of the row.
+#[derive(Debug, Deserialize, Clone)]
 pub struct Link {
     pub start: i32,
     pub end: i32,
     pub mode: TravelMode,
+    /// Length in kilometres, the straight-line distance between the places when absent.
+    #[serde(default)]
+    pub length: Option<f64>,
+    /// Travel time in minutes, derived from the length and mode speed when absent.
+    #[serde(default)]
+    pub minutes: Option<f64>,
+    /// Cost or fare of travelling the link.
+    #[serde(default)]
+    pub cost: Option<f64>,
+    /// Number of passengers or vehicles the link can carry.
+    #[serde(default)]
+    pub capacity: Option<u32>,
+    /// Whether the link may only be travelled from `start` to `end`.
+    #[serde(default)]
+    pub one_way: Option<bool>,
+}
+
+impl Link {
+    /// A two-way link with none of the optional attributes.
+    pub fn new(start: i32, end: i32, mode: TravelMode) -> Self {
+        Self {
+            start,
+            end,
+            mode,
+            length: None,
+            minutes: None,
+            cost: None,
+            capacity: None,
+            one_way: None,
+        }
+    }
+
+    pub fn is_one_way(&self) -> bool {
+        self.one_way.unwrap_or(false)
+    }
+
+    /// Checks that the given length, time and cost are finite and not negative.
+    pub fn validate(&self) -> Result<(), String> {
+        let attributes = [
+            ("length", self.length),
+            ("minutes", self.minutes),
+            ("cost", self.cost),
+        ];
+
+        for (name, value) in attributes {
+            match value {
+                Some(value) if !value.is_finite() => {
+                    return Err(format!(
+                        "link {} must be a finite number, got {}",
+                        name, value
+                    ));
+                }
+                Some(value) if value < 0.0 => {
+                    return Err(format!("link {} must not be negative, got {}", name, value));
+                }
+                _ => {}
+            }
+        }
+        Ok(())
+    }
 }
 
 #[derive(Debug, Deserialize, Clone)]
@@ -34,56 +104,76 @@ pub struct PlaceDto {
 }
 
 impl PlaceDto {
-    pub fn into_place(self) -> Place {
-        let utm_grid = self.convert_to_grid();
+    pub fn into_place(self, projection: Projection) -> Place {
+        let (eastings, northings) = projection.project(self.latitude, self.longitude);
 
         Place::new(
             self.name,
             self.id,
-            utm_grid.get_northing(),
-            utm_grid.get_easting(),
+            self.latitude,
+            self.longitude,
+            northings,
+            eastings,
         )
     }
 
-    fn convert_to_grid(&self) -> coord_transforms::structs::utm_grid::utm_grid {
-        let ellipsoid = geo_ellipsoid::geo_ellipsoid::new(
-            geo_ellipsoid::WGS84_SEMI_MAJOR_AXIS_METERS,
-            geo_ellipsoid::WGS84_FLATTENING,
-        );
-        let lat: f64 = self.latitude;
-        let long: f64 = self.longitude;
-        let ll_vec: Vector2<f64> = Vector2::new(lat.to_radians(), long.to_radians());
-
-        ll2utm(&ll_vec, &ellipsoid)
+    /// Whether the latitude and longitude are in range.
+    pub fn has_valid_coordinates(&self) -> bool {
+        (-90.0..=90.0).contains(&self.latitude) && (-180.0..=180.0).contains(&self.longitude)
     }
 }
 
-#[derive(Debug, Deserialize, Clone, PartialEq)]
+/// A place in the network, with its latitude/longitude in degrees and its position
+/// in metres on the projected grid.
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
 pub struct Place {
     pub name: String,
     pub id: i32,
+    pub latitude: f64,
+    pub longitude: f64,
     pub northings: f64,
     pub eastings: f64,
 }
 
 impl Place {
-    pub fn new(name: String, id: i32, northings: f64, eastings: f64) -> Self {
+    pub fn new(
+        name: String,
+        id: i32,
+        latitude: f64,
+        longitude: f64,
+        northings: f64,
+        eastings: f64,
+    ) -> Self {
         Self {
             name,
             id,
+            latitude,
+            longitude,
             northings,
             eastings,
         }
     }
+
+    /// Straight-line distance in kilometres between the grid positions of two places.
+    pub fn distance(&self, other: &Place) -> f64 {
+        let dx = self.eastings - other.eastings;
+        let dy = self.northings - other.northings;
+
+        (dx * dx + dy * dy).sqrt() / 1000.0
+    }
 }
 
 impl Display for Place {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{:?}", self)
+        write!(
+            f,
+            "Place {{ name: {:?}, id: {}, northings: {:?}, eastings: {:?} }}",
+            self.name, self.id, self.northings, self.eastings
+        )
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum Command {
     MaxDist,
     MaxLink,
@@ -92,4 +182,60 @@ pub enum Command {
     Check(TravelMode, Vec<i32>),
     FindRoute(TravelMode, i32, i32),
     FindShortestRoute(TravelMode, i32, i32),
+    FindShortestDistanceRoute(TravelMode, i32, i32),
+    FindFastestRoute(TravelMode, i32, i32),
+    /// The shortest route by distance through the given places in order.
+    FindRouteVia(TravelMode, Vec<i32>),
+    /// Up to the given number of the shortest routes by distance, shortest first.
+    FindAlternativeRoutes(TravelMode, i32, i32, usize),
+    /// Up to the given number of routes sharing at most the given fraction of their
+    /// distance with the shortest route and at most the given multiple of its distance.
+    FindDistinctRoutes(TravelMode, i32, i32, usize, f64, f64),
+}
+
+/// Writes the command as it would appear in a command file.
+impl Display for Command {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let ids = |places: &[i32]| {
+            let ids: Vec<String> = places.iter().map(|x| x.to_string()).collect();
+            ids.join(" ")
+        };
+
+        match self {
+            Command::MaxDist => write!(f, "MaxDist"),
+            Command::MaxLink => write!(f, "MaxLink"),
+            Command::FindDist(a, b) => write!(f, "FindDist {} {}", a, b),
+            Command::FindNeighbour(place) => write!(f, "FindNeighbour {}", place),
+            Command::Check(mode, places) => write!(f, "Check {} {}", mode, ids(places)),
+            Command::FindRoute(mode, start, goal) => {
+                write!(f, "FindRoute {} {} {}", mode, start, goal)
+            }
+            Command::FindShortestRoute(mode, start, goal) => {
+                write!(f, "FindShortestRoute {} {} {}", mode, start, goal)
+            }
+            Command::FindShortestDistanceRoute(mode, start, goal) => {
+                write!(f, "FindShortestDistanceRoute {} {} {}", mode, start, goal)
+            }
+            Command::FindFastestRoute(mode, start, goal) => {
+                write!(f, "FindFastestRoute {} {} {}", mode, start, goal)
+            }
+            Command::FindRouteVia(mode, places) => {
+                write!(f, "FindRouteVia {} {}", mode, ids(places))
+            }
+            Command::FindAlternativeRoutes(mode, start, goal, count) => {
+                write!(
+                    f,
+                    "FindAlternativeRoutes {} {} {} {}",
+                    mode, start, goal, count
+                )
+            }
+            Command::FindDistinctRoutes(mode, start, goal, count, max_overlap, max_stretch) => {
+                write!(
+                    f,
+                    "FindDistinctRoutes {} {} {} {} {} {}",
+                    mode, start, goal, count, max_overlap, max_stretch
+                )
+            }
+        }
+    }
 }
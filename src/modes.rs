@@ -0,0 +1,179 @@
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::convert::TryFrom;
+
+use serde::{Deserialize, Serialize};
+
+use crate::models::TravelMode;
+
+/// A travel mode and the rules for journeys in it.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct ModeDefinition {
+    pub name: TravelMode,
+    /// Link modes a journey in this mode may use.
+    pub links: BTreeSet<TravelMode>,
+    /// Average speed in km/h.
+    pub speed: f64,
+    /// Colour of this mode's links in DOT exports.
+    pub colour: String,
+}
+
+/// The travel modes that commands and links may use.
+///
+/// Stored as a JSON array of `ModeDefinition`s, e.g.
+/// `[{ "name": "Bus", "links": ["Bus", "Ship"], "speed": 30.0, "colour": "#2A9D8F" }, ...]`.
+/// The default holds the six modes described in the README.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(try_from = "Vec<ModeDefinition>", into = "Vec<ModeDefinition>")]
+pub struct ModeRegistry {
+    modes: Vec<ModeDefinition>,
+    /// Position of each mode in `modes` by name.
+    index: HashMap<TravelMode, usize>,
+}
+
+impl ModeRegistry {
+    /// Creates a registry, checking the definitions as `validate` does.
+    pub fn new(modes: Vec<ModeDefinition>) -> Result<Self, String> {
+        if modes.is_empty() {
+            return Err("no travel modes defined".to_string());
+        }
+
+        let mut index = HashMap::new();
+        for (i, mode) in modes.iter().enumerate() {
+            if index.insert(mode.name.clone(), i).is_some() {
+                return Err(format!("{} is defined more than once", mode.name));
+            }
+        }
+
+        let registry = Self { modes, index };
+        registry.validate()?;
+        Ok(registry)
+    }
+
+    /// Creates a registry from the earlier format, which maps each of the default
+    /// modes to the link modes it may use, e.g. `{ "Bus": ["Bus", "Ship"], ... }`.
+    ///
+    /// Speeds and colours are taken from the default modes, so only those modes may be
+    /// listed.
+    pub fn from_links(links: BTreeMap<TravelMode, BTreeSet<TravelMode>>) -> Result<Self, String> {
+        let defaults = Self::default();
+
+        let modes = links
+            .into_iter()
+            .map(|(name, links)| match defaults.get(name.name()) {
+                Some(default) => Ok(ModeDefinition {
+                    links,
+                    ..default.clone()
+                }),
+                None => Err(format!(
+                    "{} is not a default mode, define it with a speed and colour in an array of modes",
+                    name
+                )),
+            })
+            .collect::<Result<_, _>>()?;
+
+        Self::new(modes)
+    }
+
+    /// The definitions in the order they were given.
+    pub fn modes(&self) -> &[ModeDefinition] {
+        &self.modes
+    }
+
+    pub fn get(&self, name: &str) -> Option<&ModeDefinition> {
+        self.index
+            .get(&TravelMode::new(name))
+            .map(|&i| &self.modes[i])
+    }
+
+    pub fn contains(&self, mode: &TravelMode) -> bool {
+        self.index.contains_key(mode)
+    }
+
+    /// Whether a journey in `mode` may use a link of `link_mode`.
+    pub fn can_traverse(&self, mode: &TravelMode, link_mode: &TravelMode) -> bool {
+        self.index
+            .get(mode)
+            .is_some_and(|&i| self.modes[i].links.contains(link_mode))
+    }
+
+    /// Checks that mode names are single words, that every mode may use at least one
+    /// link mode, that those link modes are defined and that speeds are positive.
+    ///
+    /// Names are unique as the registry is keyed by them, see `new`.
+    pub fn validate(&self) -> Result<(), String> {
+        for mode in &self.modes {
+            let name = mode.name.name();
+            if name.is_empty() || name.contains(char::is_whitespace) {
+                return Err(format!("invalid travel mode name '{}'", name));
+            }
+        }
+
+        for mode in &self.modes {
+            if mode.links.is_empty() {
+                return Err(format!("{} may not use any links", mode.name));
+            }
+            if let Some(link) = mode.links.iter().find(|link| !self.contains(link)) {
+                return Err(format!("{} may use undefined mode {}", mode.name, link));
+            }
+            if mode.speed.is_nan() || mode.speed <= 0.0 {
+                return Err(format!("{} must have a positive speed", mode.name));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl TryFrom<Vec<ModeDefinition>> for ModeRegistry {
+    type Error = String;
+
+    fn try_from(modes: Vec<ModeDefinition>) -> Result<Self, Self::Error> {
+        Self::new(modes)
+    }
+}
+
+impl From<ModeRegistry> for Vec<ModeDefinition> {
+    fn from(registry: ModeRegistry) -> Self {
+        registry.modes
+    }
+}
+
+// 1. A rail or ship journey may only use Arcs of the corresponding mode;
+// 2. A bus journey may use bus and ship Arcs, while a car journey may use car, bus and ship Arcs;
+// 3. A bike journey may use bike Arcs and Arcs defined in 1 and 2;
+// 4. A foot journey may use any Arc.
+
+impl Default for ModeRegistry {
+    fn default() -> Self {
+        let definitions: [(&str, &[&str], f64, &str); 6] = [
+            (
+                "Foot",
+                &["Foot", "Bike", "Car", "Bus", "Ship", "Rail"],
+                5.0,
+                "#772A53",
+            ),
+            (
+                "Bike",
+                &["Bike", "Car", "Bus", "Ship", "Rail"],
+                15.0,
+                "#E9C46A",
+            ),
+            ("Car", &["Car", "Bus", "Ship"], 60.0, "#E76F51"),
+            ("Bus", &["Bus", "Ship"], 40.0, "#2A9D8F"),
+            ("Ship", &["Ship"], 30.0, "#9D7892"),
+            ("Rail", &["Rail"], 90.0, "#073B74"),
+        ];
+
+        let modes = definitions
+            .iter()
+            .map(|(name, links, speed, colour)| ModeDefinition {
+                name: TravelMode::new(name),
+                links: links.iter().map(|link| TravelMode::new(link)).collect(),
+                speed: *speed,
+                colour: colour.to_string(),
+            })
+            .collect();
+
+        Self::new(modes).expect("the default modes are valid")
+    }
+}
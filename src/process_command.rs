@@ -5,24 +5,83 @@ use ordered_float::OrderedFloat;
 
 use crate::{
     algorithms::{
-        convex_hull, dijkstra, find_path,
-        graph::{Edge, EdgeRef, Graph, Node, NodeIndex},
+        astar,
+        astar::straight_line_heuristic,
+        bidirectional_dijkstra, convex_hull,
+        csr::FrozenGraph,
+        dijkstra, distinct_paths,
+        graph::{EdgeRef, Graph, NodeIndex},
+        k_shortest_paths,
+        visit::Neighbours,
+        DistinctPathLimits,
     },
+    command_result::{
+        AlternativeRoute, CheckLeg, CommandResult, DistinctRoute, RouteLeg, TimedLeg, ViaSegment,
+    },
+    distance::DistanceModel,
+    mode_view::ModeView,
     models::{Command, Link, Place, TravelMode},
+    modes::ModeRegistry,
+    process_error::ProcessError,
 };
 
 #[derive(Clone)]
 pub struct CommandProcessor {
-    graph: Graph<Place, Link>,
+    graph: FrozenGraph<Place, Link>,
     id_map: HashMap<i32, NodeIndex>,
+    modes: ModeRegistry,
+    distance_model: DistanceModel,
 }
 
 impl CommandProcessor {
+    /// Freezes `graph`, since commands only ever read from it.
     pub fn new(graph: Graph<Place, Link>, id_map: HashMap<i32, NodeIndex>) -> Self {
-        Self { graph, id_map }
+        Self {
+            graph: graph.freeze(),
+            id_map,
+            modes: ModeRegistry::default(),
+            distance_model: DistanceModel::default(),
+        }
+    }
+
+    /// Replaces the default travel modes.
+    pub fn with_modes(mut self, modes: ModeRegistry) -> Self {
+        self.modes = modes;
+        self
+    }
+
+    pub fn modes(&self) -> &ModeRegistry {
+        &self.modes
+    }
+
+    /// Replaces how distances between places are measured, projected by default.
+    pub fn with_distance_model(mut self, distance_model: DistanceModel) -> Self {
+        self.distance_model = distance_model;
+        self
+    }
+
+    pub fn distance_model(&self) -> DistanceModel {
+        self.distance_model
+    }
+
+    /// The network the commands run against.
+    pub fn graph(&self) -> &FrozenGraph<Place, Link> {
+        &self.graph
     }
 
-    pub fn process(&self, command: Command) -> String {
+    /// The network as seen by a journey in `mode`.
+    pub fn mode_view(
+        &self,
+        mode: &TravelMode,
+    ) -> Result<ModeView<'_, FrozenGraph<Place, Link>>, ProcessError> {
+        if !self.modes.contains(mode) {
+            return Err(ProcessError::UnknownTravelMode(mode.clone()));
+        }
+
+        Ok(ModeView::new(&self.graph, mode, &self.modes))
+    }
+
+    pub fn process(&self, command: Command) -> Result<CommandResult, ProcessError> {
         match command {
             Command::MaxDist => self.max_dist(),
             Command::MaxLink => self.max_link(),
@@ -33,18 +92,35 @@ impl CommandProcessor {
                 self.find_shortest_route(mode, start, dest)
             }
             Command::FindRoute(mode, start, dest) => self.find_route(mode, start, dest),
+            Command::FindShortestDistanceRoute(mode, start, dest) => {
+                self.find_shortest_distance_route(mode, start, dest)
+            }
+            Command::FindFastestRoute(mode, start, dest) => {
+                self.find_fastest_route(mode, start, dest)
+            }
+            Command::FindRouteVia(mode, places) => self.find_route_via(mode, &places),
+            Command::FindAlternativeRoutes(mode, start, dest, count) => {
+                self.find_alternative_routes(mode, start, dest, count)
+            }
+            Command::FindDistinctRoutes(mode, start, dest, count, overlap, stretch) => {
+                self.find_distinct_routes(mode, start, dest, count, overlap, stretch)
+            }
         }
     }
 
-    fn max_dist(&self) -> String {
-        let places: Vec<Place> = self
-            .graph
-            .raw_nodes()
-            .iter()
-            .map(|place| place.data.clone())
-            .collect();
+    fn max_dist(&self) -> Result<CommandResult, ProcessError> {
+        let places = self.graph.raw_nodes();
 
-        let hull = &convex_hull(places.as_slice());
+        const MIN_HULL_PLACES: usize = 4;
+        if places.len() < MIN_HULL_PLACES {
+            return Err(ProcessError::TooFewPlaces {
+                count: places.len(),
+                required: MIN_HULL_PLACES,
+            });
+        }
+
+        // The hull is taken over grid positions, the pair is then measured by the distance model.
+        let hull = &convex_hull(places);
 
         let mut pair = (&hull[0], &hull[1]);
         let mut max_dist = -1.0;
@@ -57,210 +133,513 @@ impl CommandProcessor {
             }
         }
 
-        let dist = max_dist.sqrt() / 1000.0;
-        format!("MaxDist\n{}, {}, {}", pair.0, pair.1, dist)
+        Ok(CommandResult::MaxDist {
+            a: pair.0.clone(),
+            b: pair.1.clone(),
+            distance: max_dist,
+        })
     }
 
-    fn max_link(&self) -> String {
+    fn max_link(&self) -> Result<CommandResult, ProcessError> {
         let max = self
             .graph
             .raw_edges()
             .iter()
             .max_by_key(|edge| {
-                let dist = self.edge_to_distance(edge);
+                let dist = self.link_length(&edge.data);
                 OrderedFloat(dist)
             })
-            .unwrap();
+            .ok_or(ProcessError::NoLinks)?;
 
-        let dist = self.edge_to_distance(max);
-        let a = self.graph.get_node(max.source).unwrap().data.id;
-        let b = self.graph.get_node(max.destination).unwrap().data.id;
+        let dist = self.link_length(&max.data);
+        let a = self.graph.get_node(max.source).unwrap().id;
+        let b = self.graph.get_node(max.destination).unwrap().id;
 
-        format!("MaxLink\n{},{},{:.1}", a, b, dist)
+        Ok(CommandResult::MaxLink {
+            a,
+            b,
+            distance: dist,
+        })
     }
 
-    fn find_neighbour(&self, id: i32) -> String {
-        let node_id = self.id_map.get(&id).unwrap();
-        let node = self.graph.get_node(*node_id).unwrap();
-
-        let mut output = format!("FindNeighbour {}", node.data.id);
+    fn find_neighbour(&self, id: i32) -> Result<CommandResult, ProcessError> {
+        let node_id = self.node_index(id)?;
+        let node = self.graph.get_node(node_id).unwrap();
 
-        for neighbour in self.graph.edges(*node_id) {
-            let dest = self.graph.get_node(neighbour.nodes[1]).unwrap();
-            output = format!("{}\n{}", output, dest.data.id);
-        }
+        let neighbours = self
+            .graph
+            .edges(node_id)
+            .map(|neighbour| self.graph.get_node(neighbour.nodes[1]).unwrap().id)
+            .collect();
 
-        output
+        Ok(CommandResult::FindNeighbour {
+            place: node.id,
+            neighbours,
+        })
     }
 
-    fn check(&self, mode: TravelMode, node_ids: &[i32]) -> String {
-        let indexes: Vec<&usize> = node_ids
+    fn check(&self, mode: TravelMode, node_ids: &[i32]) -> Result<CommandResult, ProcessError> {
+        if node_ids.is_empty() {
+            return Err(ProcessError::EmptyCheck);
+        }
+
+        let indexes = node_ids
             .iter()
-            .map(|x| self.id_map.get(x).unwrap())
-            .collect();
+            .map(|x| self.node_index(*x))
+            .collect::<Result<Vec<NodeIndex>, ProcessError>>()?;
 
-        let string_ids: Vec<String> = node_ids.iter().map(|x| x.to_string()).collect();
-        let mut output = format!("Check {} {}", mode, string_ids.join(" "));
+        let view = self.mode_view(&mode)?;
+        let mut legs = vec![];
 
         for i in 0..indexes.len() - 1 {
-            let current_id = *indexes[i];
-            let next_id = *indexes[i + 1];
+            let current_id = indexes[i];
+            let next_id = indexes[i + 1];
 
-            let connects = self
-                .graph
-                .edges(current_id)
-                .filter(|x| can_traverse(&mode, &x.data.mode))
-                .any(|x| x.destination() == next_id);
+            let connects = view.edges(current_id).any(|x| x.destination() == next_id);
 
             let current_node = self.graph.get_node(current_id).unwrap();
             let next_node = self.graph.get_node(next_id).unwrap();
 
-            let outcome = if connects { "PASS" } else { "FAIL" };
-            output = format!(
-                "{}\n{},{},{}",
-                output, current_node.data.id, next_node.data.id, outcome
-            );
+            legs.push(CheckLeg {
+                from: current_node.id,
+                to: next_node.id,
+                connects,
+            });
         }
 
-        output
+        Ok(CommandResult::Check {
+            mode,
+            places: node_ids.to_vec(),
+            legs,
+        })
     }
 
-    fn find_shortest_route(&self, mode: TravelMode, start: i32, goal: i32) -> String {
-        let start_node = self.id_map.get(&start).unwrap();
-        let goal_node = self.id_map.get(&goal).unwrap();
-
-        let routes = dijkstra(
-            &self.graph,
-            *start_node,
-            Some(*goal_node),
+    fn find_shortest_route(
+        &self,
+        mode: TravelMode,
+        start: i32,
+        goal: i32,
+    ) -> Result<CommandResult, ProcessError> {
+        let start_node = self.node_index(start)?;
+        let goal_node = self.node_index(goal)?;
+
+        let nodes = match bidirectional_dijkstra(
+            &self.mode_view(&mode)?,
+            start_node,
+            goal_node,
             |_| 1,
-            |x| can_traverse(&mode, &x.mode),
-        );
+            |_| true,
+        ) {
+            Some((_, nodes)) => nodes,
+            None => {
+                return Err(ProcessError::Disconnected {
+                    from: start,
+                    to: goal,
+                });
+            }
+        };
+
+        let route = nodes
+            .into_iter()
+            .map(|i| self.graph.get_node(i).unwrap().clone())
+            .collect();
 
-        let mut nodes = vec![goal_node];
-        let mut curr = goal_node;
+        Ok(CommandResult::ShortestRoute {
+            mode,
+            start,
+            goal,
+            route,
+        })
+    }
 
-        let mut output = format!("FindShortestRoute {} {} {}", mode, start, goal);
+    fn find_shortest_distance_route(
+        &self,
+        mode: TravelMode,
+        start: i32,
+        goal: i32,
+    ) -> Result<CommandResult, ProcessError> {
+        let start_node = self.node_index(start)?;
+        let goal_node = self.node_index(goal)?;
+        let view = self.mode_view(&mode)?;
+
+        let nodes = match bidirectional_dijkstra(
+            &view,
+            start_node,
+            goal_node,
+            |x| OrderedFloat(self.link_length(x)),
+            |_| true,
+        ) {
+            Some((_, nodes)) => nodes,
+            None => {
+                return Err(ProcessError::Disconnected {
+                    from: start,
+                    to: goal,
+                });
+            }
+        };
+
+        Ok(CommandResult::DistanceRoute {
+            mode,
+            start,
+            goal,
+            legs: self.distance_legs(&view, &nodes),
+        })
+    }
 
-        while curr != start_node {
-            match routes.get(curr) {
-                Some(pair) => {
-                    curr = &pair.1;
-                    nodes.push(curr);
+    fn find_route_via(
+        &self,
+        mode: TravelMode,
+        place_ids: &[i32],
+    ) -> Result<CommandResult, ProcessError> {
+        let indexes = place_ids
+            .iter()
+            .map(|x| self.node_index(*x))
+            .collect::<Result<Vec<NodeIndex>, ProcessError>>()?;
+        let view = self.mode_view(&mode)?;
+
+        let segments: Vec<ViaSegment> = indexes
+            .iter()
+            .zip(place_ids)
+            .tuple_windows()
+            .map(|((a, from), (b, to))| {
+                let routes = dijkstra(
+                    &view,
+                    *a,
+                    Some(*b),
+                    |x| OrderedFloat(self.link_length(x)),
+                    |_| true,
+                );
+
+                ViaSegment {
+                    from: *from,
+                    to: *to,
+                    legs: routes
+                        .path_to(*b)
+                        .map(|nodes| self.distance_legs(&view, &nodes)),
                 }
-                None => {
-                    output = format!("{} \nFail", output);
+            })
+            .collect();
 
-                    return output;
+        let route = segments
+            .iter()
+            .map(|x| x.legs.as_ref())
+            .collect::<Option<Vec<_>>>()
+            .map(|legs| {
+                let mut places = vec![self.graph.get_node(indexes[0]).unwrap().clone()];
+                places.extend(legs.into_iter().flatten().map(|leg| leg.to.clone()));
+                places
+            });
+
+        Ok(CommandResult::RouteVia {
+            mode,
+            places: place_ids.to_vec(),
+            segments,
+            route,
+        })
+    }
+
+    /// The legs along `nodes`, each over the shortest link between its places.
+    fn distance_legs(
+        &self,
+        view: &ModeView<'_, FrozenGraph<Place, Link>>,
+        nodes: &[NodeIndex],
+    ) -> Vec<RouteLeg> {
+        nodes
+            .iter()
+            .tuple_windows()
+            .map(|(a, b)| {
+                // There may be several links between two places, the route uses the shortest.
+                let distance = view
+                    .edges(*a)
+                    .filter(|x| x.destination() == *b)
+                    .map(|x| self.link_length(x.data))
+                    .min_by_key(|x| OrderedFloat(*x))
+                    .unwrap();
+
+                RouteLeg {
+                    from: self.graph.get_node(*a).unwrap().clone(),
+                    to: self.graph.get_node(*b).unwrap().clone(),
+                    distance,
                 }
+            })
+            .collect()
+    }
+
+    fn find_fastest_route(
+        &self,
+        mode: TravelMode,
+        start: i32,
+        goal: i32,
+    ) -> Result<CommandResult, ProcessError> {
+        let start_node = self.node_index(start)?;
+        let goal_node = self.node_index(goal)?;
+        let view = self.mode_view(&mode)?;
+
+        let routes = dijkstra(
+            &view,
+            start_node,
+            Some(goal_node),
+            |x| OrderedFloat(self.link_minutes(x)),
+            |_| true,
+        );
+
+        let nodes = match routes.path_to(goal_node) {
+            Some(nodes) => nodes,
+            None => {
+                return Err(ProcessError::Disconnected {
+                    from: start,
+                    to: goal,
+                });
             }
+        };
+
+        let legs = nodes
+            .iter()
+            .tuple_windows()
+            .map(|(a, b)| {
+                // There may be several links between two places, the route uses the fastest.
+                let link = view
+                    .edges(*a)
+                    .filter(|x| x.destination() == *b)
+                    .map(|x| x.data)
+                    .min_by_key(|link| OrderedFloat(self.link_minutes(link)))
+                    .unwrap();
+
+                TimedLeg {
+                    from: self.graph.get_node(*a).unwrap().clone(),
+                    to: self.graph.get_node(*b).unwrap().clone(),
+                    mode: link.mode.clone(),
+                    distance: self.link_length(link),
+                    minutes: self.link_minutes(link),
+                }
+            })
+            .collect();
+
+        Ok(CommandResult::FastestRoute {
+            mode,
+            start,
+            goal,
+            legs,
+        })
+    }
+
+    fn find_alternative_routes(
+        &self,
+        mode: TravelMode,
+        start: i32,
+        goal: i32,
+        count: usize,
+    ) -> Result<CommandResult, ProcessError> {
+        let start_node = self.node_index(start)?;
+        let goal_node = self.node_index(goal)?;
+
+        let routes: Vec<AlternativeRoute> = k_shortest_paths(
+            &self.mode_view(&mode)?,
+            start_node,
+            goal_node,
+            count,
+            |x| OrderedFloat(self.link_length(x)),
+            |_| true,
+        )
+        .into_iter()
+        .map(|(distance, nodes)| AlternativeRoute {
+            places: nodes
+                .into_iter()
+                .map(|i| self.graph.get_node(i).unwrap().clone())
+                .collect(),
+            distance: distance.into_inner(),
+        })
+        .collect();
+
+        if routes.is_empty() {
+            return Err(ProcessError::Disconnected {
+                from: start,
+                to: goal,
+            });
         }
-        nodes.reverse();
 
-        for i in nodes {
-            let node = self.graph.get_node(*i).unwrap();
-            output = format!("{}\n{}", output, &node.data.to_string());
+        Ok(CommandResult::AlternativeRoutes {
+            mode,
+            start,
+            goal,
+            count,
+            routes,
+        })
+    }
+
+    fn find_distinct_routes(
+        &self,
+        mode: TravelMode,
+        start: i32,
+        goal: i32,
+        count: usize,
+        max_overlap: f64,
+        max_stretch: f64,
+    ) -> Result<CommandResult, ProcessError> {
+        let start_node = self.node_index(start)?;
+        let goal_node = self.node_index(goal)?;
+
+        let limits = DistinctPathLimits {
+            max_overlap,
+            max_stretch,
+            ..DistinctPathLimits::default()
+        };
+        let routes: Vec<DistinctRoute> = distinct_paths(
+            &self.mode_view(&mode)?,
+            start_node,
+            goal_node,
+            count,
+            limits,
+            |x| self.link_length(x),
+            |_| true,
+        )
+        .into_iter()
+        .map(|path| DistinctRoute {
+            places: path
+                .nodes
+                .into_iter()
+                .map(|i| self.graph.get_node(i).unwrap().clone())
+                .collect(),
+            distance: path.cost,
+            overlap: path.overlap,
+        })
+        .collect();
+
+        if routes.is_empty() {
+            return Err(ProcessError::Disconnected {
+                from: start,
+                to: goal,
+            });
         }
 
-        output
+        Ok(CommandResult::DistinctRoutes {
+            mode,
+            start,
+            goal,
+            count,
+            max_overlap,
+            max_stretch,
+            routes,
+        })
     }
 
-    fn find_distance(&self, a: i32, b: i32) -> String {
-        let node_a = self.index_to_node(a);
-        let node_b = self.index_to_node(b);
+    fn find_distance(&self, a: i32, b: i32) -> Result<CommandResult, ProcessError> {
+        let node_a = self.index_to_node(a)?;
+        let node_b = self.index_to_node(b)?;
 
-        let dist = self.distance(&node_a.data, &node_b.data);
-        format!(
-            "FindDist\n{},{},{:.3}",
-            node_a.data.name, node_b.data.name, dist
-        )
+        Ok(CommandResult::FindDist {
+            a: node_a.clone(),
+            b: node_b.clone(),
+            distance: self.distance(node_a, node_b),
+        })
     }
 
     fn distance(&self, a: &Place, b: &Place) -> f64 {
-        let dx = a.eastings - b.eastings;
-        let dy = a.northings - b.northings;
+        self.distance_model.distance(a, b)
+    }
+
+    /// The given length of `link`, or the distance between its places when it has none.
+    fn link_length(&self, link: &Link) -> f64 {
+        if let Some(length) = link.length {
+            return length;
+        }
 
-        let dist = (dx * dx + dy * dy).sqrt();
+        // Links are only added to the graph when both of their places exist.
+        let a = self.graph.get_node(self.id_map[&link.start]).unwrap();
+        let b = self.graph.get_node(self.id_map[&link.end]).unwrap();
 
-        dist / 1000.0
+        self.distance(a, b)
     }
 
-    fn index_to_node(&self, id: i32) -> &Node<Place> {
-        let index = self.id_map.get(&id).unwrap();
-        self.graph.get_node(*index).unwrap()
+    /// The factor the distance to the goal is scaled by so that it never overestimates
+    /// the length of a route in `mode`.
+    ///
+    /// A given length may be shorter than the distance between a link's places, so this
+    /// is the smallest ratio of the two over the links `mode` may use, and 1 when none
+    /// is shorter.
+    fn heuristic_scale(&self, mode: &TravelMode) -> f64 {
+        self.graph
+            .raw_edges()
+            .iter()
+            .filter(|x| self.modes.can_traverse(mode, &x.data.mode))
+            .filter_map(|x| {
+                let length = x.data.length?;
+                let a = self.graph.get_node(x.source).unwrap();
+                let b = self.graph.get_node(x.destination).unwrap();
+                let distance = self.distance(a, b);
+
+                (distance > 0.0).then(|| length / distance)
+            })
+            .fold(1.0, f64::min)
     }
 
-    fn edge_to_distance(&self, edge: &Edge<Link>) -> f64 {
-        let a = self.graph.get_node(edge.source).unwrap();
-        let b = self.graph.get_node(edge.destination).unwrap();
+    /// The given travel time of `link`, or the minutes to travel it at the average
+    /// speed of its mode when it has none.
+    fn link_minutes(&self, link: &Link) -> f64 {
+        if let Some(minutes) = link.minutes {
+            return minutes;
+        }
 
-        self.distance(&a.data, &b.data)
-    }
+        // Mode views only yield links whose mode is in the registry.
+        let speed = self.modes.get(link.mode.name()).unwrap().speed;
 
-    fn find_route(&self, mode: TravelMode, start: i32, goal: i32) -> String {
-        let start_node = self.id_map.get(&start).unwrap();
-        let goal_node = self.id_map.get(&goal).unwrap();
-
-        let routes = find_path(
-            &self.graph,
-            *start_node,
-            *goal_node,
-            |a, b| {
-                let dx = a.eastings - b.eastings;
-                let dy = a.northings - b.northings;
-
-                dx * dx + dy * dy
-            },
-            |x| can_traverse(&mode, &x.mode),
-        );
+        self.link_length(link) / speed * 60.0
+    }
 
-        let mut nodes = vec![goal_node];
-        let mut curr = goal_node;
+    fn node_index(&self, id: i32) -> Result<NodeIndex, ProcessError> {
+        self.id_map
+            .get(&id)
+            .copied()
+            .ok_or(ProcessError::UnknownPlace(id))
+    }
 
-        let mut output = format!("FindRoute {} {} {}", mode, start, goal);
+    fn index_to_node(&self, id: i32) -> Result<&Place, ProcessError> {
+        let index = self.node_index(id)?;
+        Ok(self.graph.get_node(index).unwrap())
+    }
 
-        while curr != start_node {
-            match routes.get(curr) {
-                Some(pair) => {
-                    curr = &pair.1;
-                    nodes.push(curr);
-                }
-                None => {
-                    output = format!("{} \nFail", output);
+    fn find_route(
+        &self,
+        mode: TravelMode,
+        start: i32,
+        goal: i32,
+    ) -> Result<CommandResult, ProcessError> {
+        let start_node = self.node_index(start)?;
+        let goal_node = self.node_index(goal)?;
+
+        let goal_place = self.graph.get_node(goal_node).unwrap();
+        let heuristic = straight_line_heuristic(goal_place, self.distance_model);
+        let scale = self.heuristic_scale(&mode);
+
+        let routes = astar(
+            &self.mode_view(&mode)?,
+            start_node,
+            goal_node,
+            |x| OrderedFloat(self.link_length(x)),
+            |x| OrderedFloat(scale * heuristic(x).0),
+            |_| true,
+        );
 
-                    return output;
-                }
+        let nodes = match routes.path_to(goal_node) {
+            Some(nodes) => nodes,
+            None => {
+                return Err(ProcessError::Disconnected {
+                    from: start,
+                    to: goal,
+                });
             }
-        }
-        nodes.reverse();
+        };
 
-        for i in nodes {
-            let node = self.graph.get_node(*i).unwrap();
-            output = format!("{}\n{}", output, &node.data.to_string());
-        }
-
-        output
-    }
-}
+        let route = nodes
+            .into_iter()
+            .map(|i| self.graph.get_node(i).unwrap().clone())
+            .collect();
 
-//TODO Use flags, recursion or comparison???.
-
-// 1. A rail or ship journey may only use Arcs of the corresponding mode;
-// 2. A bus journey may use bus and ship Arcs, while a car journey may use car, bus and ship Arcs;
-// 3. A bike journey may use bike Arcs and Arcs defined in 1 and 2;
-// 4. A foot journey may use any Arc.
-
-fn can_traverse(mode: &TravelMode, edge_mode: &TravelMode) -> bool {
-    match mode {
-        TravelMode::Rail => *edge_mode == TravelMode::Rail,
-        TravelMode::Ship => *edge_mode == TravelMode::Ship,
-        TravelMode::Bus => *edge_mode == TravelMode::Bus || *edge_mode == TravelMode::Ship,
-        TravelMode::Car => {
-            *edge_mode == TravelMode::Car
-                || *edge_mode == TravelMode::Bus
-                || *edge_mode == TravelMode::Ship
-        }
-        TravelMode::Bike => *edge_mode != TravelMode::Foot,
-        TravelMode::Foot => true,
+        Ok(CommandResult::Route {
+            mode,
+            start,
+            goal,
+            route,
+        })
     }
 }
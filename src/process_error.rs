@@ -0,0 +1,57 @@
+use std::fmt::{self, Display};
+
+use crate::models::{Command, TravelMode};
+
+/// Reasons a `Command` could not be processed against the loaded network.
+///
+/// The text output reports a `Disconnected` route command as the command followed by
+/// `Fail`, as it always has, so existing command files give the same output. Other
+/// errors, and every error in JSON output, are reported by their message.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ProcessError {
+    /// The command referenced a place id that is not in the network.
+    UnknownPlace(i32),
+    /// The command used a travel mode that is not in the mode registry.
+    UnknownTravelMode(TravelMode),
+    /// A `Check` command was given no places to check.
+    EmptyCheck,
+    /// The network has no links, so `MaxLink` has no link to report.
+    NoLinks,
+    /// `MaxDist` needs at least `required` places to build a convex hull.
+    TooFewPlaces { count: usize, required: usize },
+    /// A route command found no route from `from` to `to` in its travel mode.
+    /// `FindRouteVia` instead marks each unreachable segment in its result, so the
+    /// segments that can be reached are still reported.
+    Disconnected { from: i32, to: i32 },
+}
+
+impl ProcessError {
+    /// The text output for `command` failing with this error.
+    pub fn text_output(&self, command: &Command) -> String {
+        match self {
+            ProcessError::Disconnected { .. } => format!("{} \nFail", command),
+            _ => format!("Error: {}", self),
+        }
+    }
+}
+
+impl Display for ProcessError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ProcessError::UnknownPlace(id) => write!(f, "unknown place id {}", id),
+            ProcessError::UnknownTravelMode(mode) => write!(f, "unknown travel mode {}", mode),
+            ProcessError::EmptyCheck => write!(f, "check requires at least one place"),
+            ProcessError::NoLinks => write!(f, "network has no links"),
+            ProcessError::TooFewPlaces { count, required } => write!(
+                f,
+                "network has {} places but at least {} are required",
+                count, required
+            ),
+            ProcessError::Disconnected { from, to } => {
+                write!(f, "no route from {} to {}", from, to)
+            }
+        }
+    }
+}
+
+impl std::error::Error for ProcessError {}
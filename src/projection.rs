@@ -0,0 +1,231 @@
+use std::fmt::{self, Display};
+
+/// A reference ellipsoid given by its semi-major axis in metres and flattening.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Ellipsoid {
+    semi_major_axis: f64,
+    flattening: f64,
+}
+
+impl Ellipsoid {
+    const WGS84: Ellipsoid = Ellipsoid {
+        semi_major_axis: 6_378_137.0,
+        flattening: 1.0 / 298.257_223_563,
+    };
+
+    const AIRY_1830: Ellipsoid = Ellipsoid {
+        semi_major_axis: 6_377_563.396,
+        flattening: 1.0 / 299.324_964_6,
+    };
+
+    fn eccentricity_squared(&self) -> f64 {
+        self.flattening * (2.0 - self.flattening)
+    }
+}
+
+/// The map projection used to place latitude/longitude on a flat grid of
+/// eastings and northings in metres.
+///
+/// Every place is projected with the same projection, so grid distances are
+/// consistent across the whole network even when it spans several UTM zones.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Projection {
+    /// A single UTM zone on WGS84, extended past its edges where places lie outside it.
+    Utm { zone: u8, south: bool },
+    /// The Ordnance Survey National Grid on OSGB36.
+    BritishNationalGrid,
+}
+
+impl Projection {
+    /// The UTM zone and hemisphere containing the centre of `points`, given as
+    /// `(latitude, longitude)` in degrees, or zone 30 north when there are none.
+    ///
+    /// Longitudes are averaged as angles, so places either side of the antimeridian
+    /// are centred on it rather than on the prime meridian.
+    pub fn fitting<I>(points: I) -> Self
+    where
+        I: IntoIterator<Item = (f64, f64)>,
+    {
+        let (mut count, mut latitude) = (0.0, 0.0);
+        let (mut sin, mut cos) = (0.0, 0.0);
+        for (lat, lon) in points {
+            count += 1.0;
+            latitude += lat;
+            sin += lon.to_radians().sin();
+            cos += lon.to_radians().cos();
+        }
+
+        if count == 0.0 {
+            return Projection::Utm {
+                zone: 30,
+                south: false,
+            };
+        }
+
+        Projection::Utm {
+            zone: utm_zone(sin.atan2(cos).to_degrees()),
+            south: latitude / count < 0.0,
+        }
+    }
+
+    /// Projects a WGS84 latitude/longitude in degrees to `(eastings, northings)` in metres.
+    pub fn project(&self, latitude: f64, longitude: f64) -> (f64, f64) {
+        match *self {
+            Projection::Utm { zone, south } => TransverseMercator {
+                ellipsoid: Ellipsoid::WGS84,
+                origin_latitude: 0.0,
+                central_meridian: zone as f64 * 6.0 - 183.0,
+                scale: 0.9996,
+                false_easting: 500_000.0,
+                false_northing: if south { 10_000_000.0 } else { 0.0 },
+            }
+            .project(latitude, longitude),
+            Projection::BritishNationalGrid => {
+                let (latitude, longitude) = wgs84_to_osgb36(latitude, longitude);
+
+                TransverseMercator {
+                    ellipsoid: Ellipsoid::AIRY_1830,
+                    origin_latitude: 49.0,
+                    central_meridian: -2.0,
+                    scale: 0.999_601_271_7,
+                    false_easting: 400_000.0,
+                    false_northing: -100_000.0,
+                }
+                .project(latitude, longitude)
+            }
+        }
+    }
+}
+
+impl Display for Projection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Projection::Utm { zone, south } => {
+                write!(f, "utm:{}{}", zone, if *south { "S" } else { "N" })
+            }
+            Projection::BritishNationalGrid => write!(f, "bng"),
+        }
+    }
+}
+
+/// The UTM zone, 1 to 60, whose 6 degree band contains `longitude`.
+pub fn utm_zone(longitude: f64) -> u8 {
+    let zone = ((longitude + 180.0) / 6.0).floor() as i64 % 60 + 1;
+    zone.clamp(1, 60) as u8
+}
+
+#[derive(Debug, Clone, Copy)]
+struct TransverseMercator {
+    ellipsoid: Ellipsoid,
+    origin_latitude: f64,
+    central_meridian: f64,
+    scale: f64,
+    false_easting: f64,
+    false_northing: f64,
+}
+
+impl TransverseMercator {
+    /// Krüger's series to third order in the third flattening, accurate to well
+    /// under a millimetre within a few degrees of the central meridian.
+    fn project(&self, latitude: f64, longitude: f64) -> (f64, f64) {
+        let (xi, eta) = self.conformal(latitude, longitude);
+        let (xi_origin, _) = self.conformal(self.origin_latitude, self.central_meridian);
+
+        let radius = self.scale * self.rectifying_radius();
+        let eastings = self.false_easting + radius * eta;
+        let northings = self.false_northing + radius * (xi - xi_origin);
+
+        (eastings, northings)
+    }
+
+    /// The rectifying radius, the meridian length divided by 2π.
+    fn rectifying_radius(&self) -> f64 {
+        let n = self.third_flattening();
+        let n2 = n * n;
+
+        self.ellipsoid.semi_major_axis / (1.0 + n) * (1.0 + n2 / 4.0 + n2 * n2 / 64.0)
+    }
+
+    fn third_flattening(&self) -> f64 {
+        let f = self.ellipsoid.flattening;
+        f / (2.0 - f)
+    }
+
+    /// Unscaled `(ξ, η)` coordinates of a point on the transverse Mercator plane.
+    fn conformal(&self, latitude: f64, longitude: f64) -> (f64, f64) {
+        let n = self.third_flattening();
+        let alpha = [
+            n / 2.0 - 2.0 / 3.0 * n * n + 5.0 / 16.0 * n * n * n,
+            13.0 / 48.0 * n * n - 3.0 / 5.0 * n * n * n,
+            61.0 / 240.0 * n * n * n,
+        ];
+
+        let e = self.ellipsoid.eccentricity_squared().sqrt();
+        let phi = latitude.to_radians();
+        let lambda = (longitude - self.central_meridian).to_radians();
+
+        let t = (phi.sin().atanh() - e * (e * phi.sin()).atanh()).sinh();
+        let xi_prime = t.atan2(lambda.cos());
+        let eta_prime = (lambda.sin() / (1.0 + t * t).sqrt()).atanh();
+
+        let mut xi = xi_prime;
+        let mut eta = eta_prime;
+        for (j, alpha) in alpha.iter().enumerate() {
+            let k = 2.0 * (j + 1) as f64;
+            xi += alpha * (k * xi_prime).sin() * (k * eta_prime).cosh();
+            eta += alpha * (k * xi_prime).cos() * (k * eta_prime).sinh();
+        }
+
+        (xi, eta)
+    }
+}
+
+/// Converts a WGS84 latitude/longitude in degrees to OSGB36 with the Ordnance
+/// Survey's Helmert transformation, accurate to a few metres across Great Britain.
+fn wgs84_to_osgb36(latitude: f64, longitude: f64) -> (f64, f64) {
+    const TRANSLATION: [f64; 3] = [-446.448, 125.157, -542.060];
+    const SCALE_PPM: f64 = 20.4894;
+    // Rotations in arc seconds.
+    const ROTATION: [f64; 3] = [-0.1502, -0.2470, -0.8421];
+
+    let [x, y, z] = to_cartesian(Ellipsoid::WGS84, latitude, longitude);
+
+    let s = 1.0 + SCALE_PPM * 1e-6;
+    let [rx, ry, rz] = ROTATION.map(|r| (r / 3600.0).to_radians());
+    let [tx, ty, tz] = TRANSLATION;
+
+    let cartesian = [
+        tx + s * x - rz * y + ry * z,
+        ty + rz * x + s * y - rx * z,
+        tz - ry * x + rx * y + s * z,
+    ];
+
+    from_cartesian(Ellipsoid::AIRY_1830, cartesian)
+}
+
+/// Earth-centred cartesian coordinates in metres of a point at zero height.
+fn to_cartesian(ellipsoid: Ellipsoid, latitude: f64, longitude: f64) -> [f64; 3] {
+    let (phi, lambda) = (latitude.to_radians(), longitude.to_radians());
+    let e2 = ellipsoid.eccentricity_squared();
+    let nu = ellipsoid.semi_major_axis / (1.0 - e2 * phi.sin().powi(2)).sqrt();
+
+    [
+        nu * phi.cos() * lambda.cos(),
+        nu * phi.cos() * lambda.sin(),
+        nu * (1.0 - e2) * phi.sin(),
+    ]
+}
+
+/// Latitude/longitude in degrees of earth-centred cartesian coordinates, ignoring height.
+fn from_cartesian(ellipsoid: Ellipsoid, [x, y, z]: [f64; 3]) -> (f64, f64) {
+    let e2 = ellipsoid.eccentricity_squared();
+    let p = (x * x + y * y).sqrt();
+
+    let mut phi = z.atan2(p * (1.0 - e2));
+    for _ in 0..10 {
+        let nu = ellipsoid.semi_major_axis / (1.0 - e2 * phi.sin().powi(2)).sqrt();
+        phi = (z + e2 * nu * phi.sin()).atan2(p);
+    }
+
+    (phi.to_degrees(), y.atan2(x).to_degrees())
+}
@@ -0,0 +1,85 @@
+use std::io::{self, BufRead, Write};
+
+use crate::{
+    modes::ModeRegistry,
+    process_command::CommandProcessor,
+    startup::parser::{self, ParseDiagnostic},
+};
+
+const PROMPT: &str = "> ";
+
+/// Reads commands line by line from `input`, writing each result to `output` as soon
+/// as it is processed, until `exit` or the end of the input.
+pub fn run<R, W>(processor: &CommandProcessor, input: R, mut output: W) -> io::Result<()>
+where
+    R: BufRead,
+    W: Write,
+{
+    let mut history: Vec<String> = vec![];
+
+    write!(output, "{}", PROMPT)?;
+    output.flush()?;
+
+    for line in input.lines() {
+        let line = line?;
+        let line = line.trim();
+
+        match line {
+            "" => {}
+            "exit" | "quit" => break,
+            "help" => write_help(&mut output, processor.modes())?,
+            "history" => {
+                for (number, entry) in history.iter().enumerate() {
+                    writeln!(output, "{:>4}  {}", number + 1, entry)?;
+                }
+            }
+            _ => {
+                history.push(line.to_string());
+
+                match parser::parse_line(processor.modes(), history.len(), line) {
+                    Ok(command) => match processor.process(command.clone()) {
+                        Ok(result) => writeln!(output, "{}", result)?,
+                        Err(error) => writeln!(output, "{}", error.text_output(&command))?,
+                    },
+                    Err(diagnostic) => {
+                        writeln!(output, "Error: {}", describe_parse_error(line, &diagnostic))?
+                    }
+                }
+            }
+        }
+
+        write!(output, "{}", PROMPT)?;
+        output.flush()?;
+    }
+
+    writeln!(output)
+}
+
+fn write_help<W: Write>(output: &mut W, modes: &ModeRegistry) -> io::Result<()> {
+    writeln!(output, "Commands:")?;
+    for (name, usage) in parser::COMMAND_USAGE {
+        writeln!(output, "  {}", format!("{} {}", name, usage).trim_end())?;
+    }
+    writeln!(output, "  help")?;
+    writeln!(output, "  history")?;
+    writeln!(output, "  exit")?;
+    let names: Vec<&str> = modes.modes().iter().map(|x| x.name.name()).collect();
+    writeln!(output, "Modes: {}", names.join(", "))
+}
+
+fn describe_parse_error(line: &str, diagnostic: &ParseDiagnostic) -> String {
+    let name = line.split_whitespace().next().unwrap_or_default();
+
+    match parser::COMMAND_USAGE
+        .iter()
+        .find(|(command, _)| *command == name)
+    {
+        Some((command, usage)) => format!(
+            "column {}: expected {}, usage: {}",
+            diagnostic.column,
+            diagnostic.expected,
+            format!("{} {}", command, usage).trim_end()
+        ),
+        None => format!("unknown command '{}', type 'help' to list commands", name),
+    }
+}
@@ -0,0 +1,137 @@
+use crate::{distance::DistanceModel, projection::Projection};
+
+pub const USAGE: &str = "\
+Usage: learning_graph [repl] [OPTIONS]
+
+Pass `repl` to load the network and then query it interactively.
+
+Options:
+  -p, --places <PATH>     Places file [default: Places.csv]
+  -l, --links <PATH>      Links file [default: Links.csv]
+  -c, --commands <PATH>   Commands file, read from stdin when omitted
+  -m, --modes <PATH>      JSON file defining the travel modes
+  -o, --output <PATH>     Output file, written to stdout when omitted
+  -f, --format <FORMAT>   Output format: text or json [default: text]
+  -d, --distance <MODEL>  Distance model: projected, haversine or vincenty [default: projected]
+      --projection <PROJ> Grid projection: utm, utm:<zone>[N|S] or bng [default: utm]
+      --strict            Reject the commands if any line fails to parse
+  -h, --help              Print this message";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OutputFormat {
+    Text,
+    Json,
+}
+
+/// Options for a run of the binary, parsed from its command line arguments.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Options {
+    pub places: String,
+    pub links: String,
+    pub commands: Option<String>,
+    pub modes: Option<String>,
+    pub output: Option<String>,
+    pub format: OutputFormat,
+    pub distance: DistanceModel,
+    /// The projection for place grid positions, fitted to the places when `None`.
+    pub projection: Option<Projection>,
+    pub strict: bool,
+    pub repl: bool,
+    pub help: bool,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Self {
+            places: "Places.csv".to_string(),
+            links: "Links.csv".to_string(),
+            commands: None,
+            modes: None,
+            output: None,
+            format: OutputFormat::Text,
+            distance: DistanceModel::Projected,
+            projection: None,
+            strict: false,
+            repl: false,
+            help: false,
+        }
+    }
+}
+
+impl Options {
+    /// Parses the arguments following the program name.
+    pub fn parse<I>(args: I) -> Result<Self, String>
+    where
+        I: IntoIterator<Item = String>,
+    {
+        let mut options = Options::default();
+        let mut args = args.into_iter().peekable();
+
+        if args.peek().map(String::as_str) == Some("repl") {
+            options.repl = true;
+            args.next();
+        }
+
+        while let Some(arg) = args.next() {
+            let mut value = || {
+                args.next()
+                    .ok_or_else(|| format!("missing value for '{}'", arg))
+            };
+
+            match arg.as_str() {
+                "-p" | "--places" => options.places = value()?,
+                "-l" | "--links" => options.links = value()?,
+                "-c" | "--commands" => options.commands = Some(value()?),
+                "-m" | "--modes" => options.modes = Some(value()?),
+                "-o" | "--output" => options.output = Some(value()?),
+                "-f" | "--format" => options.format = parse_format(&value()?)?,
+                "-d" | "--distance" => options.distance = parse_distance_model(&value()?)?,
+                "--projection" => options.projection = parse_projection(&value()?)?,
+                "--strict" => options.strict = true,
+                "-h" | "--help" => options.help = true,
+                _ => return Err(format!("unexpected argument '{}'", arg)),
+            }
+        }
+
+        Ok(options)
+    }
+}
+
+fn parse_format(format: &str) -> Result<OutputFormat, String> {
+    match format {
+        "text" => Ok(OutputFormat::Text),
+        "json" => Ok(OutputFormat::Json),
+        _ => Err(format!("unknown output format '{}'", format)),
+    }
+}
+
+fn parse_distance_model(model: &str) -> Result<DistanceModel, String> {
+    match model {
+        "projected" => Ok(DistanceModel::Projected),
+        "haversine" => Ok(DistanceModel::Haversine),
+        "vincenty" => Ok(DistanceModel::Vincenty),
+        _ => Err(format!("unknown distance model '{}'", model)),
+    }
+}
+
+/// Parses `bng`, `utm` for the zone fitting the places, or a fixed zone such as `utm:30`
+/// or `utm:31S`, with the hemisphere in either case.
+fn parse_projection(projection: &str) -> Result<Option<Projection>, String> {
+    let error = || format!("unknown projection '{}'", projection);
+
+    let zone = match projection {
+        "bng" => return Ok(Some(Projection::BritishNationalGrid)),
+        "utm" => return Ok(None),
+        _ => projection.strip_prefix("utm:").ok_or_else(error)?,
+    };
+
+    let (zone, south) = match zone.strip_suffix(['N', 'S', 'n', 's']) {
+        Some(number) => (number, zone.ends_with(['S', 's'])),
+        None => (zone, false),
+    };
+
+    match zone.parse::<u8>() {
+        Ok(zone) if (1..=60).contains(&zone) => Ok(Some(Projection::Utm { zone, south })),
+        _ => Err(error()),
+    }
+}
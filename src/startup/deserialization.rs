@@ -1,55 +1,279 @@
-use std::fs;
+use std::{
+    collections::HashSet,
+    fmt::{self, Display},
+    fs,
+};
 
-use csv::ReaderBuilder;
+use csv::{Position, ReaderBuilder};
+use serde::de::DeserializeOwned;
 
 use crate::{
     models::{Command, Link, Place, PlaceDto},
-    startup::parser::parse_command,
+    modes::ModeRegistry,
+    projection::Projection,
+    startup::{
+        load_error::{ErrorPolicy, LoadError, LoadErrorReason},
+        parser::{parse_command, parse_line, ParseDiagnostic},
+    },
 };
 
-pub fn read_places(path: &str) -> Vec<Place> {
-    let mut rdr = ReaderBuilder::default()
-        .has_headers(false)
-        .from_path(path)
-        .unwrap();
+/// Reads the places file, projecting every place onto the grid with `projection`,
+/// or with the UTM zone at the centre of the places when it is `None`.
+pub fn read_places(
+    path: &str,
+    policy: ErrorPolicy,
+    projection: Option<Projection>,
+) -> Result<Vec<Place>, Vec<LoadError>> {
+    let (records, mut errors): (Vec<(u64, PlaceDto)>, _) = read_records(path, policy, false);
 
+    let mut ids = HashSet::new();
     let mut collection = vec![];
-    for result in rdr.deserialize() {
-        let mut record: PlaceDto = result.unwrap();
+    for (line, record) in records {
+        if policy == ErrorPolicy::FailFast && !errors.is_empty() {
+            break;
+        }
 
-        // TODO. Have to subtract 1 such that all places all have a longitude of less than 0.
-        // Otherwise the few places with a positive longitude will have highly inaccurate eastings.
-        record.longitude -= 1.0;
+        let reason = if !ids.insert(record.id) {
+            LoadErrorReason::DuplicatePlace(record.id)
+        } else if !record.has_valid_coordinates() {
+            LoadErrorReason::InvalidCoordinates(record.latitude, record.longitude)
+        } else {
+            collection.push(record);
+            continue;
+        };
 
-        collection.push(record.into_place());
+        errors.push(LoadError::new(Some(path), Some(line), reason));
+    }
+
+    if !errors.is_empty() {
+        return Err(errors);
+    }
+
+    let projection = projection.unwrap_or_else(|| {
+        Projection::fitting(collection.iter().map(|x| (x.latitude, x.longitude)))
+    });
+
+    Ok(collection
+        .into_iter()
+        .map(|record| record.into_place(projection))
+        .collect())
+}
+
+/// Reads the links file, rejecting links whose travel mode is not in `modes`.
+///
+/// Each link comes with the line of the file it was read from.
+pub fn read_links(
+    path: &str,
+    policy: ErrorPolicy,
+    modes: &ModeRegistry,
+) -> Result<Vec<(u64, Link)>, Vec<LoadError>> {
+    let (links, errors) = read_valid_links(path, policy, modes);
+
+    if errors.is_empty() {
+        Ok(links)
+    } else {
+        Err(errors)
     }
-    collection
 }
 
-pub fn read_links(path: &str) -> Vec<Link> {
+/// The links of the links file that are valid, with their lines, and the errors for
+/// those that are not.
+pub(crate) fn read_valid_links(
+    path: &str,
+    policy: ErrorPolicy,
+    modes: &ModeRegistry,
+) -> (Vec<(u64, Link)>, Vec<LoadError>) {
+    // Trailing optional columns may be omitted, so rows can differ in length.
+    let (records, mut errors): (Vec<(u64, Link)>, _) = read_records(path, policy, true);
+
+    let mut collection = vec![];
+    for (line, link) in records {
+        if policy == ErrorPolicy::FailFast && !errors.is_empty() {
+            break;
+        }
+
+        if !modes.contains(&link.mode) {
+            errors.push(LoadError::new(
+                Some(path),
+                Some(line),
+                LoadErrorReason::UnknownTravelMode(link.mode.to_string()),
+            ));
+            continue;
+        }
+
+        if let Err(message) = link.validate() {
+            errors.push(LoadError::new(
+                Some(path),
+                Some(line),
+                LoadErrorReason::InvalidRecord(message),
+            ));
+            continue;
+        }
+
+        collection.push((line, link));
+    }
+
+    (collection, errors)
+}
+
+/// Reads and validates a JSON travel modes file, either an array of mode definitions
+/// or the earlier object of link modes for each default mode.
+pub fn read_modes(path: &str) -> Result<ModeRegistry, LoadError> {
+    let contents = fs::read_to_string(path)
+        .map_err(|err| LoadError::new(Some(path), None, LoadErrorReason::Io(err.to_string())))?;
+
+    let invalid =
+        |message| LoadError::new(Some(path), None, LoadErrorReason::InvalidModes(message));
+
+    // An object is the earlier format mapping each default mode to its link modes.
+    // serde_json includes the line and column in its messages.
+    if contents.trim_start().starts_with('{') {
+        let links = serde_json::from_str(&contents).map_err(|err| invalid(err.to_string()))?;
+        return ModeRegistry::from_links(links).map_err(invalid);
+    }
+
+    serde_json::from_str(&contents).map_err(|err| invalid(err.to_string()))
+}
+
+/// Deserializes the records of a headerless CSV file along with the line each starts on,
+/// returning the records that parsed and the errors for those that did not.
+///
+/// A `flexible` file may have rows with different numbers of fields.
+fn read_records<T>(
+    path: &str,
+    policy: ErrorPolicy,
+    flexible: bool,
+) -> (Vec<(u64, T)>, Vec<LoadError>)
+where
+    T: DeserializeOwned,
+{
+    let contents = match fs::read(path) {
+        Ok(contents) => contents,
+        Err(err) => {
+            let reason = LoadErrorReason::Io(err.to_string());
+            return (vec![], vec![LoadError::new(Some(path), None, reason)]);
+        }
+    };
+
+    // The reader gives a record after blank lines the position of the first of them.
+    let line_of = |pos: &Position| {
+        let blank_lines = contents[pos.byte() as usize..]
+            .iter()
+            .take_while(|x| **x == b'\n' || **x == b'\r')
+            .filter(|x| **x == b'\n')
+            .count();
+        pos.line() + blank_lines as u64
+    };
+
     let mut rdr = ReaderBuilder::default()
         .has_headers(false)
-        .from_path(path)
-        .unwrap();
+        .flexible(flexible)
+        .from_reader(contents.as_slice());
 
+    let mut errors = vec![];
     let mut collection = vec![];
-    for result in rdr.deserialize() {
-        let record: Link = result.unwrap();
-        collection.push(record);
+    for result in rdr.records() {
+        let parsed = match result {
+            Ok(record) => {
+                let line = record.position().map(line_of);
+                record
+                    .deserialize(None)
+                    .map(|value| (line.unwrap_or_default(), value))
+                    .map_err(|err| LoadError::from_csv(path, line, err))
+            }
+            Err(err) => {
+                let line = err.position().map(line_of);
+                Err(LoadError::from_csv(path, line, err))
+            }
+        };
+
+        match parsed {
+            Ok(value) => collection.push(value),
+            Err(err) => {
+                errors.push(err);
+                if policy == ErrorPolicy::FailFast {
+                    break;
+                }
+            }
+        }
     }
 
-    collection
+    (collection, errors)
+}
+
+/// How `read_commands` treats lines that are not valid commands.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Strictness {
+    /// Reject the file, reporting every line that fails to parse or has trailing input.
+    Strict,
+    /// Skip lines that fail to parse and ignore trailing input, so `MaxDistX` is run
+    /// as `MaxDist`.
+    Lenient,
 }
 
-pub fn read_commands(path: &str) -> Result<Vec<Command>, String> {
-    let contents = fs::read_to_string(path).expect("Something went wrong reading the file");
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CommandsError {
+    Io(String),
+    Parse(Vec<ParseDiagnostic>),
+}
+
+impl Display for CommandsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CommandsError::Io(message) => write!(f, "{}", message),
+            CommandsError::Parse(diagnostics) => {
+                let lines: Vec<String> = diagnostics.iter().map(|x| x.to_string()).collect();
+                write!(f, "{}", lines.join("\n"))
+            }
+        }
+    }
+}
+
+impl std::error::Error for CommandsError {}
 
+pub fn read_commands(
+    path: &str,
+    strictness: Strictness,
+    modes: &ModeRegistry,
+) -> Result<Vec<Command>, CommandsError> {
+    let contents = fs::read_to_string(path).map_err(|err| {
+        CommandsError::Io(format!("could not read commands from {}: {}", path, err))
+    })?;
+
+    parse_commands(&contents, strictness, modes).map_err(CommandsError::Parse)
+}
+
+/// Parses one command per line, ignoring blank lines.
+pub fn parse_commands(
+    contents: &str,
+    strictness: Strictness,
+    modes: &ModeRegistry,
+) -> Result<Vec<Command>, Vec<ParseDiagnostic>> {
     let mut commands = vec![];
-    for line in contents.lines() {
-        let parsed = parse_command(line.as_bytes());
-        if let Ok(command) = parsed {
-            commands.push(command.1);
+    let mut diagnostics = vec![];
+
+    for (index, line) in contents.lines().enumerate() {
+        match strictness {
+            Strictness::Strict => {
+                if line.trim().is_empty() {
+                    continue;
+                }
+                match parse_line(modes, index + 1, line) {
+                    Ok(command) => commands.push(command),
+                    Err(diagnostic) => diagnostics.push(diagnostic),
+                }
+            }
+            Strictness::Lenient => {
+                if let Ok((_, command)) = parse_command(modes, line.as_bytes()) {
+                    commands.push(command);
+                }
+            }
         }
     }
-    Ok(commands)
+
+    if diagnostics.is_empty() {
+        Ok(commands)
+    } else {
+        Err(diagnostics)
+    }
 }
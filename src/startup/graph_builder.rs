@@ -3,9 +3,25 @@ use std::collections::HashMap;
 use crate::{
     algorithms::graph::{Graph, NodeIndex},
     models::{Link, Place},
+    modes::ModeRegistry,
+    projection::Projection,
+    startup::{
+        deserialization::{read_places, read_valid_links},
+        load_error::{ErrorPolicy, LoadError, LoadErrorReason},
+    },
 };
 
-pub fn build(nodes: Vec<Place>, edges: Vec<Link>) -> (Graph<Place, Link>, HashMap<i32, NodeIndex>) {
+type Network = (Graph<Place, Link>, HashMap<i32, NodeIndex>);
+
+/// Builds the network graph, rejecting links whose endpoints are not in `nodes`.
+///
+/// Each edge comes with the line of the links file it was read from, which errors
+/// report.
+pub fn build(
+    nodes: Vec<Place>,
+    edges: Vec<(u64, Link)>,
+    policy: ErrorPolicy,
+) -> Result<Network, Vec<LoadError>> {
     let mut graph = Graph::new();
     let mut map = HashMap::new();
 
@@ -15,11 +31,79 @@ pub fn build(nodes: Vec<Place>, edges: Vec<Link>) -> (Graph<Place, Link>, HashMa
         map.insert(id, node_index);
     }
 
-    for edge in edges {
-        let a = map.get(&edge.start).unwrap();
-        let b = map.get(&edge.end).unwrap();
-        graph.add_edge(*a, *b, edge);
+    let mut errors = vec![];
+    for (line, edge) in edges {
+        let reason = match (map.get(&edge.start), map.get(&edge.end)) {
+            (Some(a), Some(b)) if a != b => {
+                if edge.is_one_way() {
+                    graph.add_directed_edge(*a, *b, edge);
+                } else {
+                    graph.add_edge(*a, *b, edge);
+                }
+                continue;
+            }
+            (Some(_), Some(_)) => LoadErrorReason::SelfLink(edge.start),
+            (None, _) => LoadErrorReason::DanglingLink(edge.start),
+            (_, None) => LoadErrorReason::DanglingLink(edge.end),
+        };
+
+        errors.push(LoadError::new(None, Some(line), reason));
+        if policy == ErrorPolicy::FailFast {
+            break;
+        }
+    }
+
+    if errors.is_empty() {
+        Ok((graph, map))
+    } else {
+        Err(errors)
+    }
+}
+
+/// Reads the places and links files and builds the network from them, accepting
+/// links in the travel modes defined in `modes`.
+///
+/// Places are projected with `projection`, or the UTM zone that best fits them when
+/// it is `None`.
+pub fn load(
+    places_path: &str,
+    links_path: &str,
+    policy: ErrorPolicy,
+    modes: &ModeRegistry,
+    projection: Option<Projection>,
+) -> Result<Network, Vec<LoadError>> {
+    let places = read_places(places_path, policy, projection);
+    let (links, mut errors) = read_valid_links(links_path, policy, modes);
+
+    let places = match places {
+        Ok(places) if errors.is_empty() || policy == ErrorPolicy::CollectAll => places,
+        Ok(_) => return Err(errors),
+        Err(place_errors) if policy == ErrorPolicy::FailFast => return Err(place_errors),
+        Err(mut place_errors) => {
+            place_errors.append(&mut errors);
+            return Err(place_errors);
+        }
+    };
+
+    // Links that were read are still checked against the places, so every problem
+    // in the links file is reported at once, in file order.
+    match build(places, links, policy) {
+        Ok(network) if errors.is_empty() => Ok(network),
+        Ok(_) => Err(errors),
+        Err(build_errors) => {
+            errors.extend(in_file(build_errors, links_path));
+            errors.sort_by_key(|error| error.line);
+            Err(errors)
+        }
     }
+}
 
-    (graph, map)
+fn in_file(errors: Vec<LoadError>, path: &str) -> Vec<LoadError> {
+    errors
+        .into_iter()
+        .map(|mut error| {
+            error.file.get_or_insert_with(|| path.to_string());
+            error
+        })
+        .collect()
 }
@@ -0,0 +1,101 @@
+use std::fmt::{self, Display};
+
+/// Whether loading should stop at the first problem or report every problem found.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ErrorPolicy {
+    FailFast,
+    CollectAll,
+}
+
+/// A problem found while loading the network, with the file and line it came from
+/// when they are known.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LoadError {
+    pub file: Option<String>,
+    pub line: Option<u64>,
+    pub reason: LoadErrorReason,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum LoadErrorReason {
+    /// The file could not be opened or read.
+    Io(String),
+    /// A record could not be parsed, e.g. a bad float or an unknown travel mode.
+    InvalidRecord(String),
+    /// A place id that already appeared earlier in the file.
+    DuplicatePlace(i32),
+    /// A place whose latitude or longitude is out of range.
+    InvalidCoordinates(f64, f64),
+    /// A link endpoint that does not match any loaded place.
+    DanglingLink(i32),
+    /// A link that starts and ends at the same place.
+    SelfLink(i32),
+    /// A link whose travel mode is not in the mode registry.
+    UnknownTravelMode(String),
+    /// The travel modes file could not be parsed or breaks a rule.
+    InvalidModes(String),
+}
+
+impl LoadError {
+    pub fn new(file: Option<&str>, line: Option<u64>, reason: LoadErrorReason) -> Self {
+        Self {
+            file: file.map(String::from),
+            line,
+            reason,
+        }
+    }
+
+    pub(crate) fn from_csv(file: &str, line: Option<u64>, error: csv::Error) -> Self {
+        let line = line.or_else(|| error.position().map(|pos| pos.line()));
+
+        let reason = match error.kind() {
+            csv::ErrorKind::Io(err) => LoadErrorReason::Io(err.to_string()),
+            csv::ErrorKind::Deserialize { err, .. } => {
+                LoadErrorReason::InvalidRecord(err.to_string())
+            }
+            csv::ErrorKind::UnequalLengths {
+                expected_len, len, ..
+            } => LoadErrorReason::InvalidRecord(format!(
+                "expected {} fields, found {}",
+                expected_len, len
+            )),
+            _ => LoadErrorReason::InvalidRecord(error.to_string()),
+        };
+
+        Self::new(Some(file), line, reason)
+    }
+}
+
+impl Display for LoadErrorReason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LoadErrorReason::Io(message) => write!(f, "could not read file: {}", message),
+            LoadErrorReason::InvalidRecord(message) => write!(f, "invalid record: {}", message),
+            LoadErrorReason::DuplicatePlace(id) => write!(f, "duplicate place id {}", id),
+            LoadErrorReason::InvalidCoordinates(latitude, longitude) => write!(
+                f,
+                "coordinates {}, {} are not a valid latitude and longitude",
+                latitude, longitude
+            ),
+            LoadErrorReason::DanglingLink(id) => write!(f, "link references unknown place {}", id),
+            LoadErrorReason::SelfLink(id) => write!(f, "link from place {} to itself", id),
+            LoadErrorReason::UnknownTravelMode(mode) => write!(f, "unknown travel mode {}", mode),
+            LoadErrorReason::InvalidModes(message) => {
+                write!(f, "invalid travel modes: {}", message)
+            }
+        }
+    }
+}
+
+impl Display for LoadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match (&self.file, self.line) {
+            (Some(file), Some(line)) => write!(f, "{}:{}: {}", file, line, self.reason),
+            (Some(file), None) => write!(f, "{}: {}", file, self.reason),
+            (None, Some(line)) => write!(f, "line {}: {}", line, self.reason),
+            (None, None) => write!(f, "{}", self.reason),
+        }
+    }
+}
+
+impl std::error::Error for LoadError {}
@@ -1,3 +1,5 @@
+pub mod cli;
 pub mod deserialization;
 pub mod graph_builder;
+pub mod load_error;
 pub mod parser;
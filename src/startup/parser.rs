@@ -1,133 +1,323 @@
-use nom::character::complete::i32;
+use std::fmt::{self, Display};
+
+use nom::character::complete::{alphanumeric1, i32};
 use nom::character::{is_digit, is_space};
+use nom::combinator::{cut, not, verify};
+use nom::error::{context, ErrorKind, ParseError, VerboseError, VerboseErrorKind};
 use nom::multi::separated_list0;
+use nom::number::complete::double;
 use nom::{
     branch::alt,
-    bytes::complete::{tag, take_while},
-    sequence::tuple,
-    IResult,
+    bytes::complete::{tag, take_till1, take_while},
+    sequence::{terminated, tuple},
+    IResult, Offset,
 };
 
 use crate::models::{Command, TravelMode};
+use crate::modes::ModeRegistry;
+
+pub type ParseResult<'a, T> = IResult<&'a [u8], T, VerboseError<&'a [u8]>>;
+
+/// Where and why a command line failed to parse.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseDiagnostic {
+    pub line: usize,
+    pub column: usize,
+    pub expected: &'static str,
+}
+
+impl Display for ParseDiagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: expected {}",
+            self.line, self.column, self.expected
+        )
+    }
+}
 
-fn parse_int(bytes: &[u8]) -> IResult<&[u8], i32> {
+fn parse_int(bytes: &[u8]) -> ParseResult<'_, i32> {
     let (remainder, digits) = take_while(is_digit)(bytes)?;
     let (_, integer) = i32(digits)?;
 
     Ok((remainder, integer))
 }
 
-fn parse_space(bytes: &[u8]) -> IResult<&[u8], &[u8]> {
+fn parse_place(bytes: &[u8]) -> ParseResult<'_, i32> {
+    context("place id", parse_int)(bytes)
+}
+
+fn parse_count(bytes: &[u8]) -> ParseResult<'_, usize> {
+    let (input, count) = context("route count", verify(parse_int, |x| *x > 0))(bytes)?;
+
+    Ok((input, count as usize))
+}
+
+fn parse_overlap(bytes: &[u8]) -> ParseResult<'_, f64> {
+    context(
+        "overlap fraction",
+        verify(double, |x| (0.0..=1.0).contains(x)),
+    )(bytes)
+}
+
+fn parse_stretch(bytes: &[u8]) -> ParseResult<'_, f64> {
+    context(
+        "stretch factor",
+        verify(double, |x| x.is_finite() && *x >= 1.0),
+    )(bytes)
+}
+
+fn parse_space(bytes: &[u8]) -> ParseResult<'_, &[u8]> {
     let (remainder, spaces) = take_while(is_space)(bytes)?;
 
     Ok((remainder, spaces))
 }
 
-fn parse_maxdist(bytes: &[u8]) -> IResult<&[u8], Command> {
+/// Matches the name of a command that takes arguments and the single space after it.
+///
+/// The name must not be the start of a longer word, so `FindRoute` does not match the
+/// start of `FindRouteVia`.
+fn keyword<'a>(name: &'static str) -> impl FnMut(&'a [u8]) -> ParseResult<'a, &'a [u8]> {
+    terminated(
+        terminated(tag(name), not(alphanumeric1)),
+        cut(context("space", tag(" "))),
+    )
+}
+
+fn parse_maxdist(bytes: &[u8]) -> ParseResult<'_, Command> {
     let (a, _) = tag("MaxDist")(bytes)?;
     Ok((a, Command::MaxDist))
 }
 
-fn parse_maxlink(bytes: &[u8]) -> IResult<&[u8], Command> {
+fn parse_maxlink(bytes: &[u8]) -> ParseResult<'_, Command> {
     let (a, _) = tag("MaxLink")(bytes)?;
     Ok((a, Command::MaxLink))
 }
 
-fn parse_finddist(bytes: &[u8]) -> IResult<&[u8], Command> {
-    let (input, (_, a, _, b)) =
-        tuple((tag("FindDist "), parse_int, parse_space, parse_int))(bytes)?;
+fn parse_finddist(bytes: &[u8]) -> ParseResult<'_, Command> {
+    let (input, (_, (a, _, b))) = tuple((
+        keyword("FindDist"),
+        cut(tuple((parse_place, parse_space, parse_place))),
+    ))(bytes)?;
 
     Ok((input, Command::FindDist(a, b)))
 }
 
-fn parse_findneighbours(bytes: &[u8]) -> IResult<&[u8], Command> {
-    let (input, (_, a)) = tuple((tag("FindNeighbour "), parse_int))(bytes)?;
+fn parse_findneighbours(bytes: &[u8]) -> ParseResult<'_, Command> {
+    let (input, (_, a)) = tuple((keyword("FindNeighbour"), cut(parse_place)))(bytes)?;
 
     Ok((input, Command::FindNeighbour(a)))
 }
 
-fn parse_places(bytes: &[u8]) -> IResult<&[u8], Vec<i32>> {
-    let (a, b) = separated_list0(tag(" "), parse_int)(bytes)?;
+fn parse_places(bytes: &[u8]) -> ParseResult<'_, Vec<i32>> {
+    let (a, b) = separated_list0(tag(" "), parse_place)(bytes)?;
 
     Ok((a, b))
 }
 
-fn parse_check(bytes: &[u8]) -> IResult<&[u8], Command> {
-    let (input, (_, mode, _, integers)) =
-        tuple((tag("Check "), parse_mode, parse_space, parse_places))(bytes)?;
+fn parse_check<'a>(modes: &ModeRegistry, bytes: &'a [u8]) -> ParseResult<'a, Command> {
+    let (input, (_, (mode, _, integers))) = tuple((
+        keyword("Check"),
+        cut(tuple((|i| parse_mode(modes, i), parse_space, parse_places))),
+    ))(bytes)?;
 
     Ok((input, Command::Check(mode, integers)))
 }
 
-fn parse_findroute(bytes: &[u8]) -> IResult<&[u8], Command> {
-    let (input, (_, mode, _, a, _, b)) = tuple((
-        tag("FindRoute "),
-        parse_mode,
+/// Parses `<mode> <place> <place>` following a route command name.
+fn parse_route_args<'a>(
+    modes: &ModeRegistry,
+    bytes: &'a [u8],
+) -> ParseResult<'a, (TravelMode, i32, i32)> {
+    let (input, (mode, _, a, _, b)) = tuple((
+        |i| parse_mode(modes, i),
         parse_space,
-        parse_int,
+        parse_place,
         parse_space,
-        parse_int,
+        parse_place,
     ))(bytes)?;
 
+    Ok((input, (mode, a, b)))
+}
+
+fn parse_findroute<'a>(modes: &ModeRegistry, bytes: &'a [u8]) -> ParseResult<'a, Command> {
+    let (input, (_, (mode, a, b))) =
+        tuple((keyword("FindRoute"), cut(|i| parse_route_args(modes, i))))(bytes)?;
+
     Ok((input, Command::FindRoute(mode, a, b)))
 }
 
-fn parse_findfastestroute(bytes: &[u8]) -> IResult<&[u8], Command> {
-    let (input, (_, mode, _, a, _, b)) = tuple((
-        tag("FindShortestRoute "),
-        parse_mode,
-        parse_space,
-        parse_int,
-        parse_space,
-        parse_int,
+fn parse_findshortestroute<'a>(modes: &ModeRegistry, bytes: &'a [u8]) -> ParseResult<'a, Command> {
+    let (input, (_, (mode, a, b))) = tuple((
+        keyword("FindShortestRoute"),
+        cut(|i| parse_route_args(modes, i)),
     ))(bytes)?;
 
     Ok((input, Command::FindShortestRoute(mode, a, b)))
 }
 
-pub fn parse_command(input: &[u8]) -> IResult<&[u8], Command> {
-    alt((
-        parse_maxdist,
-        parse_maxlink,
-        parse_finddist,
-        parse_findneighbours,
-        parse_check,
-        parse_findroute,
-        parse_findfastestroute,
-    ))(input)
+fn parse_findshortestdistanceroute<'a>(
+    modes: &ModeRegistry,
+    bytes: &'a [u8],
+) -> ParseResult<'a, Command> {
+    let (input, (_, (mode, a, b))) = tuple((
+        keyword("FindShortestDistanceRoute"),
+        cut(|i| parse_route_args(modes, i)),
+    ))(bytes)?;
+
+    Ok((input, Command::FindShortestDistanceRoute(mode, a, b)))
 }
 
-pub fn parse_mode(input: &[u8]) -> IResult<&[u8], TravelMode> {
-    alt((
-        parse_foot, parse_bike, parse_rail, parse_car, parse_bus, parse_ship,
-    ))(input)
+fn parse_findfastestroute<'a>(modes: &ModeRegistry, bytes: &'a [u8]) -> ParseResult<'a, Command> {
+    let (input, (_, (mode, a, b))) = tuple((
+        keyword("FindFastestRoute"),
+        cut(|i| parse_route_args(modes, i)),
+    ))(bytes)?;
+
+    Ok((input, Command::FindFastestRoute(mode, a, b)))
 }
 
-//TODO Replace with macros
+fn parse_findroutevia<'a>(modes: &ModeRegistry, bytes: &'a [u8]) -> ParseResult<'a, Command> {
+    let (input, (_, (mode, _, places))) = tuple((
+        keyword("FindRouteVia"),
+        cut(tuple((
+            |i| parse_mode(modes, i),
+            parse_space,
+            context("via places", verify(parse_places, |x: &[i32]| x.len() >= 3)),
+        ))),
+    ))(bytes)?;
 
-fn parse_foot(bytes: &[u8]) -> IResult<&[u8], TravelMode> {
-    let (a, _) = tag("Foot")(bytes)?;
-    Ok((a, TravelMode::Foot))
+    Ok((input, Command::FindRouteVia(mode, places)))
 }
-fn parse_bike(bytes: &[u8]) -> IResult<&[u8], TravelMode> {
-    let (a, _) = tag("Bike")(bytes)?;
-    Ok((a, TravelMode::Bike))
+
+fn parse_findalternativeroutes<'a>(
+    modes: &ModeRegistry,
+    bytes: &'a [u8],
+) -> ParseResult<'a, Command> {
+    let (input, (_, ((mode, a, b), _, k))) = tuple((
+        keyword("FindAlternativeRoutes"),
+        cut(tuple((
+            |i| parse_route_args(modes, i),
+            parse_space,
+            parse_count,
+        ))),
+    ))(bytes)?;
+
+    Ok((input, Command::FindAlternativeRoutes(mode, a, b, k)))
 }
-fn parse_rail(bytes: &[u8]) -> IResult<&[u8], TravelMode> {
-    let (a, _) = tag("Rail")(bytes)?;
-    Ok((a, TravelMode::Rail))
+
+fn parse_finddistinctroutes<'a>(modes: &ModeRegistry, bytes: &'a [u8]) -> ParseResult<'a, Command> {
+    let (input, (_, ((mode, a, b), _, k, _, overlap, _, stretch))) = tuple((
+        keyword("FindDistinctRoutes"),
+        cut(tuple((
+            |i| parse_route_args(modes, i),
+            parse_space,
+            parse_count,
+            parse_space,
+            parse_overlap,
+            parse_space,
+            parse_stretch,
+        ))),
+    ))(bytes)?;
+
+    Ok((
+        input,
+        Command::FindDistinctRoutes(mode, a, b, k, overlap, stretch),
+    ))
 }
-fn parse_car(bytes: &[u8]) -> IResult<&[u8], TravelMode> {
-    let (a, _) = tag("Car")(bytes)?;
-    Ok((a, TravelMode::Car))
+
+/// Name and argument usage of every command `parse_command` accepts.
+pub const COMMAND_USAGE: &[(&str, &str)] = &[
+    ("MaxDist", ""),
+    ("MaxLink", ""),
+    ("FindDist", "<place> <place>"),
+    ("FindNeighbour", "<place>"),
+    ("Check", "<mode> <place> <place> ..."),
+    ("FindRoute", "<mode> <place> <place>"),
+    ("FindShortestRoute", "<mode> <place> <place>"),
+    ("FindShortestDistanceRoute", "<mode> <place> <place>"),
+    ("FindFastestRoute", "<mode> <place> <place>"),
+    ("FindRouteVia", "<mode> <place> <place> <place> ..."),
+    ("FindAlternativeRoutes", "<mode> <place> <place> <count>"),
+    (
+        "FindDistinctRoutes",
+        "<mode> <place> <place> <count> <overlap> <stretch>",
+    ),
+];
+
+/// Parses a command, accepting the travel modes defined in `modes`.
+pub fn parse_command<'a>(modes: &ModeRegistry, input: &'a [u8]) -> ParseResult<'a, Command> {
+    context(
+        "command",
+        alt((
+            parse_maxdist,
+            parse_maxlink,
+            parse_finddist,
+            parse_findneighbours,
+            |i| parse_check(modes, i),
+            |i| parse_findroute(modes, i),
+            |i| parse_findshortestroute(modes, i),
+            |i| parse_findshortestdistanceroute(modes, i),
+            |i| parse_findfastestroute(modes, i),
+            |i| parse_findroutevia(modes, i),
+            |i| parse_findalternativeroutes(modes, i),
+            |i| parse_finddistinctroutes(modes, i),
+        )),
+    )(input)
 }
-fn parse_bus(bytes: &[u8]) -> IResult<&[u8], TravelMode> {
-    let (a, _) = tag("Bus")(bytes)?;
-    Ok((a, TravelMode::Bus))
+
+/// Parses a whole line as a single command, rejecting anything but trailing
+/// whitespace after it.
+pub fn parse_line(
+    modes: &ModeRegistry,
+    line_number: usize,
+    line: &str,
+) -> Result<Command, ParseDiagnostic> {
+    let input = line.as_bytes();
+    let diagnostic = |position: &[u8], expected| ParseDiagnostic {
+        line: line_number,
+        column: input.offset(position) + 1,
+        expected,
+    };
+
+    match parse_command(modes, input) {
+        Ok((remainder, command)) => match remainder.iter().position(|c| !c.is_ascii_whitespace()) {
+            Some(unconsumed) => Err(diagnostic(&remainder[unconsumed..], "end of line")),
+            None => Ok(command),
+        },
+        Err(nom::Err::Error(error)) | Err(nom::Err::Failure(error)) => {
+            // The first context is the innermost one, closest to where parsing failed.
+            let (position, expected) = error
+                .errors
+                .iter()
+                .find_map(|(position, kind)| match kind {
+                    VerboseErrorKind::Context(expected) => Some((*position, *expected)),
+                    _ => None,
+                })
+                .unwrap_or((input, "command"));
+
+            Err(diagnostic(position, expected))
+        }
+        Err(nom::Err::Incomplete(_)) => Err(diagnostic(&input[input.len()..], "command")),
+    }
 }
-fn parse_ship(bytes: &[u8]) -> IResult<&[u8], TravelMode> {
-    let (a, _) = tag("Ship")(bytes)?;
-    Ok((a, TravelMode::Ship))
+
+/// Parses the name of a travel mode defined in `modes`.
+pub fn parse_mode<'a>(modes: &ModeRegistry, input: &'a [u8]) -> ParseResult<'a, TravelMode> {
+    context("travel mode", |bytes: &'a [u8]| {
+        let (remainder, name) = take_till1(|c: u8| c.is_ascii_whitespace())(bytes)?;
+
+        match std::str::from_utf8(name)
+            .ok()
+            .and_then(|name| modes.get(name))
+        {
+            Some(mode) => Ok((remainder, mode.name.clone())),
+            None => Err(nom::Err::Error(VerboseError::from_error_kind(
+                bytes,
+                ErrorKind::Verify,
+            ))),
+        }
+    })(input)
 }
 
 #[macro_export]
@@ -142,16 +332,3 @@ macro_rules! mag {
         }
     };
 }
-// macro_rules! mode_parser {
-//     ($($t:tt, $y:ty)+) => {
-//         $(
-//         pub fn $t(input: &[u8]) -> IResult<&[u8], $t>
-//             {
-//                 let (a, _) = tag($t)(input)?;
-//                 Ok((a, $y))
-//             }
-//         )+
-//     }
-// }
-
-// mode_parser! {TransportType::Foot}
@@ -1,6 +1,8 @@
 use std::fmt;
 
 use crate::algorithms::graph::Graph;
+use crate::models::TravelMode;
+use crate::modes::ModeRegistry;
 
 pub struct Dot<'a, N, E> {
     graph: Graph<N, E>,
@@ -38,6 +40,9 @@ impl<'a, N, E> fmt::Display for Dot<'a, N, E> {
 
         for edge in self.graph.raw_edges() {
             write!(f, "{} -- {} [", edge.source, edge.destination)?;
+            if edge.directed {
+                write!(f, "dir=forward ")?;
+            }
             // edge.data.fmt(f)?;
             (self.edge_func)(f, &edge.data)?;
             writeln!(f, "]")?;
@@ -52,3 +57,36 @@ impl<'a, N, E> fmt::Display for Dot<'a, N, E> {
         Ok(())
     }
 }
+
+/// Colour of links in `mode`, black when the mode is not in `modes`.
+pub fn mode_colour<'a>(modes: &'a ModeRegistry, mode: &TravelMode) -> &'a str {
+    modes
+        .get(mode.name())
+        .map_or("#000000", |definition| definition.colour.as_str())
+}
+
+/// Writes a legend subgraph with a line in the colour of each mode in `modes`.
+pub fn write_mode_legend(f: &mut fmt::Formatter, modes: &ModeRegistry) -> fmt::Result {
+    write!(
+        f,
+        "subgraph cluster_01 {{\nlabel = \"Legend\";\nnode [shape=point]\n{{\nrank=same\n"
+    )?;
+
+    for mode in modes.modes() {
+        write!(
+            f,
+            "\"{0}0\" [style = invis];\n\"{0}1\" [style = invis];\n",
+            mode.name
+        )?;
+    }
+    writeln!(f, "}}")?;
+
+    for mode in modes.modes() {
+        writeln!(
+            f,
+            "\"{0}0\" -- \"{0}1\" [label=\"{0}\" color=\"{1}\" penwidth=\"8\"] ",
+            mode.name, mode.colour
+        )?;
+    }
+    writeln!(f, "}}")
+}